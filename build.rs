@@ -0,0 +1,184 @@
+//! Generates code from files that the game can't read on its own at runtime.
+//! - The Yarn files under `assets/dialogue`, included by `src/third_party/bevy_yarnspinner.rs`, and the voice lines
+//!   under `assets/audio/voice`, included by `src/gameplay/player/dialogue/view/voice.rs`.
+//!   Wasm cannot list the files in a directory at runtime, so we do it at compile time for all platforms.
+//! - The generic props declared in `src/props/props.ron`. TrenchBroom classes are Rust types,
+//!   so each prop becomes a struct. The structs are included by `src/props/generic.rs`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+const DIALOGUE_DIR: &str = "assets/dialogue";
+const VOICE_DIR: &str = "assets/audio/voice";
+const PROPS_MANIFEST: &str = "src/props/props.ron";
+
+fn main() {
+    // Cargo scans directories recursively, so this also picks up new and renamed files in subdirectories.
+    println!("cargo:rerun-if-changed={DIALOGUE_DIR}");
+    // Watch the parent directory so that creating the voice directory also triggers a rebuild.
+    println!("cargo:rerun-if-changed=assets/audio");
+
+    let mut yarn_files = Vec::new();
+    collect_yarn_files(Path::new(DIALOGUE_DIR), &mut yarn_files);
+    write_list("yarn_files.rs", yarn_files);
+
+    write_list("voice_lines.rs", collect_voice_lines(Path::new(VOICE_DIR)));
+
+    generate_generic_props();
+}
+
+/// Writes `items` as a sorted `&[&str]` expression to `file_name` in the output directory.
+fn write_list(file_name: &str, mut items: Vec<String>) {
+    items.sort();
+    let entries = items
+        .iter()
+        .map(|item| format!("    {item:?},\n"))
+        .collect::<String>();
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join(file_name);
+    fs::write(out_path, format!("&[\n{entries}]\n")).unwrap();
+}
+
+fn collect_yarn_files(dir: &Path, yarn_files: &mut Vec<String>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|err| panic!("Failed to read {dir:?}: {err}"));
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_yarn_files(&path, yarn_files);
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "yarn")
+        {
+            // Asset paths are relative to the assets directory and always use forward slashes.
+            let asset_path = path
+                .strip_prefix("assets")
+                .unwrap()
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            yarn_files.push(asset_path);
+        }
+    }
+}
+
+/// The line IDs of the voice lines in `dir`. A voice line is named after the ID of the line it is for.
+fn collect_voice_lines(dir: &Path) -> Vec<String> {
+    // No line is voiced yet.
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ogg"))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+        .collect()
+}
+
+/// A prop as declared in the manifest. See the manifest itself for what the fields mean.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PropDefinition {
+    name: String,
+    model: String,
+    physics: PropPhysics,
+    #[serde(default)]
+    collider: PropCollider,
+    #[serde(default)]
+    density: Option<f32>,
+    #[serde(default)]
+    pickup_rotation: Option<(f32, f32, f32)>,
+    #[serde(default)]
+    navmesh_affector: Option<bool>,
+    #[serde(default)]
+    physics_material: Option<String>,
+    #[serde(default)]
+    bases: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+enum PropPhysics {
+    Static,
+    Dynamic,
+    Nonphysical,
+}
+
+#[derive(Deserialize, Debug, Default)]
+enum PropCollider {
+    #[default]
+    ConvexHull,
+    ConvexDecomposition,
+    Trimesh,
+}
+
+fn generate_generic_props() {
+    println!("cargo:rerun-if-changed={PROPS_MANIFEST}");
+    let manifest = fs::read_to_string(PROPS_MANIFEST)
+        .unwrap_or_else(|err| panic!("Failed to read {PROPS_MANIFEST}: {err}"));
+    let props: Vec<PropDefinition> = ron::from_str(&manifest)
+        .unwrap_or_else(|err| panic!("Failed to parse {PROPS_MANIFEST}: {err}"));
+
+    let mut code = String::new();
+    for prop in &props {
+        let bases = ["Transform", "Visibility"]
+            .into_iter()
+            .chain(prop.bases.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let density = match prop.density {
+            Some(density) => format!("Some({density:?})"),
+            None => "None".to_string(),
+        };
+        let pickup_rotation = match prop.pickup_rotation {
+            Some((yaw, pitch, roll)) => format!(
+                "Some(Quat::from_euler(EulerRot::YXZ, {:?}, {:?}, {:?}))",
+                yaw.to_radians(),
+                pitch.to_radians(),
+                roll.to_radians()
+            ),
+            None => "None".to_string(),
+        };
+        let physics_material = match &prop.physics_material {
+            Some(material) => format!(", PhysicsMaterial = PhysicsMaterial::{material}"),
+            None => String::new(),
+        };
+        let navmesh_affector = prop
+            .navmesh_affector
+            .unwrap_or(prop.physics == PropPhysics::Static);
+        code += &format!(
+            r#"
+#[derive(PointClass, Component, Debug, Reflect)]
+#[reflect(QuakeClass, Component)]
+#[base({bases})]
+#[model({model:?})]
+#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
+#[require(GenericProp = GenericProp {{
+    model: {model:?},
+    physics: PropPhysics::{physics:?},
+    collider: PropCollider::{collider:?},
+    density: {density},
+    pickup_rotation: {pickup_rotation},
+    navmesh_affector: {navmesh_affector},
+}}{physics_material})]
+pub(crate) struct {name};
+"#,
+            name = prop.name,
+            model = prop.model,
+            physics = prop.physics,
+            collider = prop.collider,
+        );
+    }
+
+    // In Wasm, TrenchBroom classes are not automatically registered.
+    code += "\nfn register_generic_props(app: &mut App) {\n";
+    for prop in &props {
+        code += &format!("    app.register_type::<{}>();\n", prop.name);
+    }
+    code += "}\n";
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("generic_props.rs");
+    fs::write(out_path, code).unwrap();
+}
@@ -1,12 +1,27 @@
-use bevy::{audio::Volume, prelude::*};
+use std::any::TypeId;
+
+use bevy::{
+    audio::{SpatialScale, Volume},
+    platform::collections::HashSet,
+    prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<Music>();
     app.register_type::<SoundEffect>();
+    app.register_type::<Ambience>();
+    app.init_resource::<DuckBackgroundAudio>();
+    app.init_resource::<BackgroundAudioGain>();
 
     app.add_systems(
         Update,
-        apply_global_volume.run_if(resource_changed::<GlobalVolume>),
+        (
+            update_background_audio_gain,
+            apply_global_volume.run_if(
+                resource_changed::<GlobalVolume>.or(resource_changed::<BackgroundAudioGain>),
+            ),
+        )
+            .chain(),
     );
 }
 
@@ -36,13 +51,92 @@ pub(crate) fn sound_effect(handle: Handle<AudioSource>) -> impl Bundle {
     (AudioPlayer(handle), PlaybackSettings::DESPAWN, SoundEffect)
 }
 
+/// How quickly the sounds of props fade with distance. Characters have their own, see `gameplay::npc::sound`.
+pub(crate) const PROP_SPATIAL_SCALE: SpatialScale = SpatialScale::new(0.3);
+
+/// A sound effect audio instance that is heard from where it is, e.g. when spawned as a child of a door.
+/// It fades with distance like the other sounds of props.
+pub(crate) fn spatial_sound_effect(handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN
+            .with_spatial(true)
+            .with_spatial_scale(PROP_SPATIAL_SCALE),
+        SoundEffect,
+    )
+}
+
+/// An organizational marker component that should be added to a spawned [`AudioPlayer`] if it's in the
+/// general "ambience" category (e.g. a crackling fire, wind, machinery).
+///
+/// Like [`Music`], ambience is ducked while [`DuckBackgroundAudio`] is requested.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub(crate) struct Ambience;
+
+/// The systems that currently want [`Music`] and [`Ambience`] to be quieter, e.g. so that a voice line can be heard.
+#[derive(Resource, Debug, Default, Deref, DerefMut)]
+pub(crate) struct DuckBackgroundAudio(HashSet<TypeId>);
+
+/// The gain applied to [`Music`] and [`Ambience`] on top of the global volume.
+/// Fades towards [`BackgroundAudioGain::DUCKED`] while [`DuckBackgroundAudio`] is not empty.
+#[derive(Resource, Debug)]
+struct BackgroundAudioGain(f32);
+
+impl BackgroundAudioGain {
+    const DUCKED: f32 = 0.3;
+    /// How much the gain can change per second.
+    const FADE_SPEED: f32 = 2.0;
+}
+
+impl Default for BackgroundAudioGain {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+fn update_background_audio_gain(
+    duck: Res<DuckBackgroundAudio>,
+    mut gain: ResMut<BackgroundAudioGain>,
+    time: Res<Time>,
+) {
+    let target = if duck.is_empty() {
+        1.0
+    } else {
+        BackgroundAudioGain::DUCKED
+    };
+    if gain.0 == target {
+        return;
+    }
+    let max_delta = BackgroundAudioGain::FADE_SPEED * time.delta_secs();
+    gain.0 += (target - gain.0).clamp(-max_delta, max_delta);
+}
+
 /// [`GlobalVolume`] doesn't apply to already-running audio entities, so this system will update them.
 fn apply_global_volume(
     global_volume: Res<GlobalVolume>,
-    mut audio_query: Query<(&PlaybackSettings, &mut AudioSink)>,
+    background_gain: Res<BackgroundAudioGain>,
+    mut audio_query: Query<(&PlaybackSettings, &mut AudioSink, Has<Music>, Has<Ambience>)>,
+    mut spatial_audio_query: Query<(
+        &PlaybackSettings,
+        &mut SpatialAudioSink,
+        Has<Music>,
+        Has<Ambience>,
+    )>,
 ) {
-    for (playback, mut sink) in &mut audio_query {
-        sink.set_volume(global_volume.volume * playback.volume);
+    let volume = |playback: &PlaybackSettings, is_background: bool| {
+        let volume = global_volume.volume * playback.volume;
+        if is_background {
+            volume * Volume::Linear(background_gain.0)
+        } else {
+            volume
+        }
+    };
+    for (playback, mut sink, is_music, is_ambience) in &mut audio_query {
+        sink.set_volume(volume(playback, is_music || is_ambience));
+    }
+    for (playback, mut sink, is_music, is_ambience) in &mut spatial_audio_query {
+        sink.set_volume(volume(playback, is_music || is_ambience));
     }
 }
 
@@ -5,6 +5,7 @@ use std::any::Any as _;
 use super::input::{ForceFreeCursor, ToggleDebugUi};
 use crate::RenderLayer;
 use crate::gameplay::crosshair::CrosshairState;
+use crate::third_party::bevy_yarnspinner::InvalidYarnNodes;
 use crate::{PostPhysicsAppSystems, theme::widget};
 use avian3d::prelude::*;
 use bevy::render::view::RenderLayers;
@@ -83,6 +84,12 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         update_debug_ui_text.run_if(resource_exists_and_changed::<DebugState>),
     );
+    app.add_systems(
+        Update,
+        update_invalid_yarn_nodes_text
+            .run_if(resource_changed::<InvalidYarnNodes>)
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
     app.add_systems(
         Update,
         (
@@ -105,17 +112,33 @@ fn setup_debug_ui_text(mut commands: Commands) {
             position_type: PositionType::Absolute,
             width: Percent(100.0),
             height: Percent(100.0),
-            justify_content: JustifyContent::End,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::End,
             ..default()
         },
         Pickable::IGNORE,
-        children![(widget::label("Debug UI"), DebugUiText)],
+        children![
+            (widget::label("Debug UI"), DebugUiText),
+            (
+                Name::new("Invalid Yarn Nodes"),
+                Text::default(),
+                TextFont::from_font_size(16.0),
+                TextColor(ERROR_TEXT),
+                InvalidYarnNodesText,
+            ),
+        ],
     ));
 }
 
 #[derive(Component)]
 struct DebugUiText;
 
+/// Lists level problems that would otherwise only show up in the logs.
+#[derive(Component)]
+struct InvalidYarnNodesText;
+
+const ERROR_TEXT: Color = Color::srgb(1.0, 0.35, 0.35);
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn advance_debug_state(
     _trigger: Trigger<Started<ToggleDebugUi>>,
@@ -139,6 +162,18 @@ fn update_debug_ui_text(
     .to_string();
 }
 
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_invalid_yarn_nodes_text(
+    invalid_yarn_nodes: Res<InvalidYarnNodes>,
+    mut text: Single<&mut Text, With<InvalidYarnNodesText>>,
+) {
+    text.0 = invalid_yarn_nodes
+        .iter()
+        .map(|description| format!("Invalid YarnNode on {description}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn toggle_debug_ui(mut options: ResMut<UiDebugOptions>) {
     options.toggle();
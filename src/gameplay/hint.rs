@@ -0,0 +1,71 @@
+//! Short messages at the bottom of the screen, e.g. "It's locked." Trigger [`ShowHint`] to show one.
+//! A new hint replaces the current one. Hints fade out on their own.
+
+use bevy::{prelude::*, ui::Val::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{PostPhysicsAppSystems, screens::Screen, theme::prelude::*};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_hint_ui);
+    app.add_observer(show_hint);
+    app.add_systems(
+        Update,
+        fade_hint
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
+}
+
+/// Trigger this to show a hint to the player.
+#[derive(Event, Debug, Clone)]
+pub(crate) struct ShowHint(pub(crate) String);
+
+/// How long a hint stays fully visible, in seconds.
+const HINT_DURATION: f32 = 2.5;
+/// How long it then takes to fade out, in seconds.
+const HINT_FADE_DURATION: f32 = 0.5;
+
+#[derive(Component, Debug, Default)]
+struct HintText {
+    /// Seconds since the hint was shown.
+    age: f32,
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_hint_ui(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Hint"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Percent(100.0),
+            bottom: Percent(30.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        StateScoped(Screen::Gameplay),
+        Pickable::IGNORE,
+        children![(
+            widget::paragraph(""),
+            HintText {
+                age: HINT_DURATION + HINT_FADE_DURATION,
+            },
+        )],
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn show_hint(trigger: Trigger<ShowHint>, hint: Single<(&mut Text, &mut HintText)>) {
+    let (mut text, mut hint) = hint.into_inner();
+    text.0 = trigger.event().0.clone();
+    hint.age = 0.0;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn fade_hint(time: Res<Time>, hint: Single<(&mut TextColor, &mut HintText)>) {
+    let (mut color, mut hint) = hint.into_inner();
+    hint.age += time.delta_secs();
+    let alpha = 1.0 - ((hint.age - HINT_DURATION) / HINT_FADE_DURATION).clamp(0.0, 1.0);
+    color.0.set_alpha(alpha);
+}
@@ -0,0 +1,110 @@
+//! Locks on doors and containers. A [`Lock`] names the item that opens it.
+//! Using a [`Locked`] entity does not trigger [`Interacted`](super::Interacted), but tries to unlock it with the player's inventory instead.
+//! Opened locks are part of the save game.
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use crate::{
+    asset_tracking::LoadResource as _,
+    audio::spatial_sound_effect,
+    gameplay::{
+        hint::ShowHint,
+        player::{Player, inventory::Inventory},
+    },
+    save::{SaveGame, level_key},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Lock>();
+    app.register_type::<Locked>();
+    app.load_resource::<LockAssets>();
+    app.add_observer(setup_lock);
+    app.add_observer(try_unlock);
+}
+
+#[derive(BaseClass, Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct Lock {
+    /// The ID of the item that unlocks this. Leave empty for no lock.
+    pub(crate) locked: String,
+    /// Whether unlocking uses up the key.
+    pub(crate) consume_key: bool,
+}
+
+/// Marks an entity with a [`Lock`] that was not opened yet. Holds the [`level_key`] of the lock.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Locked(String);
+
+/// Triggered on a [`Locked`] entity when the player tries to use it.
+#[derive(Event, Debug, Clone, Copy)]
+pub(super) struct UseLocked;
+
+#[derive(Resource, Asset, Clone, TypePath)]
+struct LockAssets {
+    #[dependency]
+    rattle: Handle<AudioSource>,
+    #[dependency]
+    unlock: Handle<AudioSource>,
+}
+
+impl FromWorld for LockAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            rattle: assets.load("audio/sound_effects/lock_rattle.ogg"),
+            unlock: assets.load("audio/sound_effects/unlock.ogg"),
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_lock(
+    trigger: Trigger<OnAdd, Lock>,
+    q_lock: Query<(&Lock, &Transform)>,
+    save_game: Res<SaveGame>,
+    mut commands: Commands,
+) {
+    let (lock, transform) = q_lock.get(trigger.target()).unwrap();
+    if lock.locked.is_empty() {
+        return;
+    }
+    let key = level_key(&format!("lock:{}", lock.locked), transform.translation);
+    if !save_game.unlocked.contains(&key) {
+        commands.entity(trigger.target()).insert(Locked(key));
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn try_unlock(
+    trigger: Trigger<UseLocked>,
+    q_lock: Query<(&Lock, &Locked)>,
+    mut inventory: Single<&mut Inventory, With<Player>>,
+    mut save_game: ResMut<SaveGame>,
+    lock_assets: Res<LockAssets>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((lock, locked)) = q_lock.get(entity) else {
+        return;
+    };
+    let Some(key_name) = inventory.get(&lock.locked).map(|item| item.name.clone()) else {
+        commands.trigger(ShowHint("It's locked.".to_string()));
+        commands
+            .entity(entity)
+            .with_child(spatial_sound_effect(lock_assets.rattle.clone()));
+        return;
+    };
+    if lock.consume_key {
+        inventory.remove(&lock.locked);
+    }
+    save_game.unlocked.insert(locked.0.clone());
+    commands
+        .entity(entity)
+        .remove::<Locked>()
+        .with_child(spatial_sound_effect(lock_assets.unlock.clone()));
+    commands.trigger(ShowHint(format!("Unlocked with the {key_name}.")));
+}
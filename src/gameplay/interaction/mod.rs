@@ -0,0 +1,178 @@
+//! Anything the player can use by looking at it and pressing the interact button: characters to talk to, signs, lamps, doors, containers.
+//! An entity becomes usable by carrying an [`Interactable`]. When the player uses it, it receives an [`Interacted`] event.
+//! The modules implementing the different [`InteractionAction`]s observe that event. Locked entities have to be unlocked first, see [`lock`].
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::events::Started;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::{
+        player::{Player, camera::PlayerCamera, default_input::Interact, pickup::is_holding_prop},
+        targets::FireTarget,
+    },
+    screens::Screen,
+    third_party::bevy_yarnspinner::is_dialogue_running,
+};
+
+pub(crate) mod lock;
+pub(crate) mod nameplate;
+mod ui;
+
+use lock::{Locked, UseLocked};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Interactable>();
+    app.register_type::<InteractionPrompt>();
+
+    app.configure_sets(
+        Update,
+        (
+            InteractionSystems::UpdateOpportunity,
+            InteractionSystems::UpdateUi,
+        )
+            .chain()
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
+
+    app.add_systems(
+        Update,
+        check_for_interaction_opportunity
+            .in_set(InteractionSystems::UpdateOpportunity)
+            .run_if(
+                in_state(Screen::Gameplay)
+                    .and(not(is_dialogue_running))
+                    .and(not(is_holding_prop)),
+            ),
+    );
+
+    app.add_observer(interact);
+    app.add_observer(fire_target_on_interaction);
+
+    app.add_plugins((lock::plugin, nameplate::plugin, ui::plugin));
+}
+
+#[derive(Debug, SystemSet, Hash, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum InteractionSystems {
+    UpdateOpportunity,
+    UpdateUi,
+}
+
+/// Something the player can use.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Interactable {
+    /// Shown next to the crosshair, e.g. "Talk" or "Open".
+    pub(crate) prompt: String,
+    /// How far away the player can be when using this. Capped at [`MAX_INTERACTION_RANGE`].
+    pub(crate) range: f32,
+    pub(crate) action: InteractionAction,
+}
+
+impl Interactable {
+    pub(crate) const DEFAULT_RANGE: f32 = 3.0;
+
+    pub(crate) fn new(prompt: impl Into<String>, action: InteractionAction) -> Self {
+        Self {
+            prompt: prompt.into(),
+            range: Self::DEFAULT_RANGE,
+            action,
+        }
+    }
+}
+
+/// The farthest anything can be used from.
+pub(crate) const MAX_INTERACTION_RANGE: f32 = 10.0;
+
+/// What happens when the player uses an [`Interactable`].
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub(crate) enum InteractionAction {
+    /// Start the dialogue at the given Yarn node, see `player::dialogue`.
+    Talk(String),
+    /// Fire the given target, see [`FireTarget`].
+    FireTarget(String),
+    /// Switch something on or off, like a lamp.
+    Toggle,
+    /// Open or close something, like a door or a chest.
+    Open,
+    /// Read something, like a sign or a letter.
+    Read,
+    /// Put something into the inventory.
+    PickUp,
+    /// Sit on something, like a chair, see `gameplay::seating`.
+    Sit,
+}
+
+/// Triggered on an [`Interactable`] when the player uses it.
+#[derive(Event, Debug, Clone)]
+pub(crate) struct Interacted(pub(crate) InteractionAction);
+
+/// The entity the player is currently looking at and could use.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub(crate) struct InteractionPrompt(pub(crate) Option<(Entity, Interactable)>);
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn check_for_interaction_opportunity(
+    player_camera: Single<&GlobalTransform, With<PlayerCamera>>,
+    player: Single<Entity, With<Player>>,
+    mut interaction_prompt: Single<&mut InteractionPrompt>,
+    q_interactable: Query<&Interactable>,
+    q_parent: Query<&ChildOf>,
+    q_sensor: Query<(), With<Sensor>>,
+    spatial_query: SpatialQuery,
+) {
+    let camera_transform = player_camera.compute_transform();
+    // Walls block interactions, but trigger volumes like rooms don't.
+    let hit = spatial_query.cast_ray_predicate(
+        camera_transform.translation,
+        camera_transform.forward(),
+        MAX_INTERACTION_RANGE,
+        true,
+        &SpatialQueryFilter::default().with_excluded_entities([*player]),
+        &|entity| !q_sensor.contains(entity),
+    );
+    // The collider we hit may be a child of the entity that is interactable.
+    let opportunity = hit.and_then(|hit| {
+        std::iter::once(hit.entity)
+            .chain(q_parent.iter_ancestors(hit.entity))
+            .find_map(|entity| {
+                q_interactable
+                    .get(entity)
+                    .ok()
+                    .map(|interactable| (entity, interactable))
+            })
+            .filter(|(_, interactable)| hit.distance <= interactable.range)
+            .map(|(entity, interactable)| (entity, interactable.clone()))
+    });
+    if interaction_prompt.0 != opportunity {
+        interaction_prompt.0 = opportunity;
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn interact(
+    _trigger: Trigger<Started<Interact>>,
+    mut interaction_prompt: Single<&mut InteractionPrompt>,
+    q_locked: Query<(), With<Locked>>,
+    mut commands: Commands,
+) {
+    let Some((entity, interactable)) = interaction_prompt.0.take() else {
+        return;
+    };
+    if q_locked.contains(entity) {
+        commands.trigger_targets(UseLocked, entity);
+    } else {
+        commands.trigger_targets(Interacted(interactable.action), entity);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn fire_target_on_interaction(trigger: Trigger<Interacted>, mut commands: Commands) {
+    if let InteractionAction::FireTarget(target) = &trigger.event().0 {
+        commands.trigger(FireTarget(target.clone()));
+    }
+}
@@ -0,0 +1,257 @@
+//! Labels floating above characters and other [`Interactable`]s, showing their name and what using them would do.
+//! Each [`Nameplate`] gets its own UI node, which we move to where its entity is on screen.
+//! Nameplates fade in as the player gets closer and fade out when something is in the way.
+//! Mappers give a prop a nameplate through the [`NameplateLabel`] base class.
+
+use avian3d::prelude::*;
+use bevy::{prelude::*, ui::Val::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use super::Interactable;
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::player::{Player, camera::WorldModelCamera},
+    screens::Screen,
+    theme::palette::{HEADER_TEXT, LABEL_TEXT},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Nameplate>();
+    app.register_type::<NameplateLabel>();
+    app.add_observer(add_nameplate_from_label);
+    app.add_observer(spawn_nameplate_ui);
+    app.add_observer(despawn_nameplate_ui);
+    app.add_systems(
+        Update,
+        (update_nameplate_text, update_nameplate_position)
+            .chain()
+            .in_set(PostPhysicsAppSystems::ChangeUi)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Shows a label above this entity.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Nameplate {
+    pub(crate) name: String,
+    /// Where the label is anchored, relative to the entity.
+    pub(crate) offset: Vec3,
+}
+
+impl Nameplate {
+    pub(crate) fn new(name: impl Into<String>, offset: Vec3) -> Self {
+        Self {
+            name: name.into(),
+            offset,
+        }
+    }
+}
+
+/// Lets a mapper show a [`Nameplate`] above a prop.
+#[derive(BaseClass, Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct NameplateLabel {
+    /// The name shown above the prop. Leave empty for no nameplate.
+    pub(crate) nameplate: String,
+    /// Where the nameplate is anchored relative to the prop's origin, in meters with Y pointing up.
+    pub(crate) nameplate_offset: Vec3,
+}
+
+impl Default for NameplateLabel {
+    fn default() -> Self {
+        Self {
+            nameplate: String::new(),
+            nameplate_offset: Vec3::Y * 0.5,
+        }
+    }
+}
+
+/// Nameplates are fully visible up to this distance...
+const FADE_START: f32 = 4.0;
+/// ...and invisible beyond this one.
+const FADE_END: f32 = 8.0;
+/// How quickly nameplates fade when line of sight changes, in opacity per second.
+const FADE_SPEED: f32 = 4.0;
+const WIDTH: f32 = 300.0;
+/// Roughly the height of the name and verb together.
+const HEIGHT: f32 = 48.0;
+
+/// The UI node showing the nameplate of the given entity.
+#[derive(Component, Debug)]
+struct NameplateUi {
+    target: Entity,
+    opacity: f32,
+}
+
+#[derive(Component, Debug)]
+struct NameplateName;
+
+#[derive(Component, Debug)]
+struct NameplateVerb;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn add_nameplate_from_label(
+    trigger: Trigger<OnAdd, NameplateLabel>,
+    q_label: Query<&NameplateLabel>,
+    mut commands: Commands,
+) {
+    let label = q_label.get(trigger.target()).unwrap();
+    if label.nameplate.is_empty() {
+        return;
+    }
+    commands.entity(trigger.target()).insert(Nameplate::new(
+        label.nameplate.clone(),
+        label.nameplate_offset,
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_nameplate_ui(trigger: Trigger<OnAdd, Nameplate>, mut commands: Commands) {
+    commands.spawn((
+        Name::new("Nameplate"),
+        NameplateUi {
+            target: trigger.target(),
+            opacity: 0.0,
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            width: Px(WIDTH),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        Visibility::Hidden,
+        StateScoped(Screen::Gameplay),
+        Pickable::IGNORE,
+        children![
+            (
+                Text::default(),
+                TextFont::from_font_size(20.0),
+                TextColor(HEADER_TEXT),
+                NameplateName,
+            ),
+            (
+                Text::default(),
+                TextFont::from_font_size(14.0),
+                TextColor(LABEL_TEXT),
+                NameplateVerb,
+            ),
+        ],
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn despawn_nameplate_ui(
+    trigger: Trigger<OnRemove, Nameplate>,
+    q_nameplate_ui: Query<(Entity, &NameplateUi)>,
+    mut commands: Commands,
+) {
+    for (entity, nameplate_ui) in &q_nameplate_ui {
+        if nameplate_ui.target == trigger.target() {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_nameplate_text(
+    q_nameplate_ui: Query<(&NameplateUi, &Children)>,
+    q_target: Query<
+        (&Nameplate, Option<&Interactable>),
+        Or<(Changed<Nameplate>, Changed<Interactable>)>,
+    >,
+    mut q_name: Query<&mut Text, (With<NameplateName>, Without<NameplateVerb>)>,
+    mut q_verb: Query<&mut Text, (With<NameplateVerb>, Without<NameplateName>)>,
+) {
+    for (nameplate_ui, children) in &q_nameplate_ui {
+        let Ok((nameplate, interactable)) = q_target.get(nameplate_ui.target) else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = q_name.get_mut(child) {
+                text.0 = nameplate.name.clone();
+            }
+            if let Ok(mut text) = q_verb.get_mut(child) {
+                text.0 = interactable
+                    .map(|interactable| interactable.prompt.clone())
+                    .unwrap_or_default();
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_nameplate_position(
+    time: Res<Time>,
+    camera: Single<(&Camera, &GlobalTransform), With<WorldModelCamera>>,
+    player: Single<Entity, With<Player>>,
+    mut q_nameplate_ui: Query<(&mut NameplateUi, &mut Node, &mut Visibility, &Children)>,
+    q_target: Query<(&Nameplate, &GlobalTransform)>,
+    q_parent: Query<&ChildOf>,
+    q_sensor: Query<(), With<Sensor>>,
+    mut q_text_color: Query<&mut TextColor>,
+    spatial_query: SpatialQuery,
+) {
+    let (camera, camera_transform) = camera.into_inner();
+    let camera_position = camera_transform.translation();
+    for (mut nameplate_ui, mut node, mut visibility, children) in &mut q_nameplate_ui {
+        let Ok((nameplate, target_transform)) = q_target.get(nameplate_ui.target) else {
+            continue;
+        };
+        let anchor = target_transform.translation() + nameplate.offset;
+        let to_anchor = anchor - camera_position;
+        let distance = to_anchor.length();
+
+        // Anything between the camera and the anchor that is not part of the target itself blocks the view.
+        let target = nameplate_ui.target;
+        let blocked = Dir3::new(to_anchor).is_ok_and(|direction| {
+            spatial_query
+                .cast_ray_predicate(
+                    camera_position,
+                    direction,
+                    distance,
+                    true,
+                    &SpatialQueryFilter::default().with_excluded_entities([*player]),
+                    &|entity| {
+                        !q_sensor.contains(entity)
+                            && entity != target
+                            && !q_parent
+                                .iter_ancestors(entity)
+                                .any(|ancestor| ancestor == target)
+                    },
+                )
+                .is_some()
+        });
+        let target_opacity = if blocked {
+            0.0
+        } else {
+            1.0 - ((distance - FADE_START) / (FADE_END - FADE_START)).clamp(0.0, 1.0)
+        };
+        let max_delta = FADE_SPEED * time.delta_secs();
+        nameplate_ui.opacity +=
+            (target_opacity - nameplate_ui.opacity).clamp(-max_delta, max_delta);
+
+        // Points behind the camera have no viewport position.
+        let viewport_position = camera.world_to_viewport(camera_transform, anchor);
+        let Ok(viewport_position) = viewport_position else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        if nameplate_ui.opacity <= 0.0 {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+        *visibility = Visibility::Inherited;
+        // Anchor the bottom center of the label.
+        node.left = Px(viewport_position.x - WIDTH / 2.0);
+        node.top = Px(viewport_position.y - HEIGHT);
+        for child in children.iter() {
+            if let Ok(mut color) = q_text_color.get_mut(child) {
+                color.0.set_alpha(nameplate_ui.opacity);
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! The UI part of interactions. When something can be used, we change the crosshair and show what pressing the interact button would do.
+//! The prompt shows the input currently bound to [`Interact`] on the device the player last used.
+
+use std::any::Any as _;
+
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use super::{InteractionPrompt, InteractionSystems};
+use crate::{
+    gameplay::{
+        crosshair::CrosshairState,
+        player::default_input::{DefaultInputContext, Interact},
+    },
+    screens::Screen,
+    theme::input_glyph::{InputGlyph, LastInputDevice, input_glyph},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup_interaction_prompt);
+    app.add_systems(
+        Update,
+        (
+            update_interaction_prompt_ui,
+            update_interaction_prompt_glyph,
+        )
+            .in_set(InteractionSystems::UpdateUi)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_interaction_prompt(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Interaction Prompt"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                left: Val::Percent(50.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            StateScoped(Screen::Gameplay),
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    left: Val::Px(50.0),
+                    column_gap: Val::Px(8.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                Visibility::Hidden,
+                InteractionPrompt::default(),
+                children![
+                    (
+                        Name::new("Interaction Prompt Glyph"),
+                        Node::default(),
+                        InteractionPromptGlyph::default(),
+                    ),
+                    (Text::new(""), InteractionPromptText),
+                ],
+            ));
+        });
+}
+
+/// Holds the glyph of the bound input. Remembers which glyph it shows so that we only respawn it when that changes.
+#[derive(Component, Default)]
+struct InteractionPromptGlyph(Option<InputGlyph>);
+
+#[derive(Component)]
+struct InteractionPromptText;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_interaction_prompt_ui(
+    interaction_prompt: Single<(&mut Visibility, Ref<InteractionPrompt>)>,
+    mut text: Single<&mut Text, With<InteractionPromptText>>,
+    mut crosshair: Single<&mut CrosshairState>,
+) {
+    let (mut prompt_visibility, interaction_prompt) = interaction_prompt.into_inner();
+    if !interaction_prompt.is_changed() {
+        return;
+    }
+
+    let system_id = update_interaction_prompt_ui.type_id();
+    if let Some((_, interactable)) = &interaction_prompt.0 {
+        text.0 = interactable.prompt.clone();
+        *prompt_visibility = Visibility::Inherited;
+        crosshair.wants_square.insert(system_id);
+    } else {
+        text.0 = String::new();
+        *prompt_visibility = Visibility::Hidden;
+        crosshair.wants_square.remove(&system_id);
+    }
+}
+
+/// Rebindings and switching between keyboard and gamepad both change the glyph.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_interaction_prompt_glyph(
+    glyph_node: Single<(Entity, &mut InteractionPromptGlyph)>,
+    // The actions are removed while input is blocked, e.g. during dialogue. No prompt is shown then anyways.
+    actions: Option<Single<&Actions<DefaultInputContext>>>,
+    last_input_device: Res<LastInputDevice>,
+    mut commands: Commands,
+) {
+    let Some(actions) = actions else {
+        return;
+    };
+    let (entity, mut current_glyph) = glyph_node.into_inner();
+    let glyph = InputGlyph::for_action::<_, Interact>(&actions, *last_input_device);
+    if current_glyph.0 == glyph {
+        return;
+    }
+    commands.entity(entity).despawn_related::<Children>();
+    if let Some(glyph) = &glyph {
+        commands.entity(entity).with_child(input_glyph(glyph));
+    }
+    current_glyph.0 = glyph;
+}
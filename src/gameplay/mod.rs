@@ -7,16 +7,24 @@ use bevy::prelude::*;
 
 mod animation;
 pub(crate) mod crosshair;
+pub(crate) mod hint;
+pub(crate) mod interaction;
 pub(crate) mod level;
 pub(crate) mod npc;
 pub(crate) mod player;
+pub(crate) mod seating;
+pub(crate) mod targets;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins((
         animation::plugin,
         crosshair::plugin,
+        hint::plugin,
+        interaction::plugin,
         npc::plugin,
         player::plugin,
+        seating::plugin,
+        targets::plugin,
         // This plugin preloads the level,
         // so make sure to add it last.
         level::plugin,
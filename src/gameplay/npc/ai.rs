@@ -1,4 +1,5 @@
-//! NPC AI. In this case, the only AI is the ability to move towards the player.
+//! NPC AI. In this case, the AI moves towards the player and sits down on a nearby chair when it has nothing to do.
+//! While the player talks to an NPC, it stops and turns to face the player instead.
 
 use std::f32::consts::TAU;
 
@@ -15,7 +16,13 @@ use bevy_simple_subsecond_system::hot;
 use bevy_tnua::prelude::*;
 
 use crate::{
-    PrePhysicsAppSystems, gameplay::player::navmesh_position::LastValidPlayerNavmeshPosition,
+    PostPhysicsAppSystems, PrePhysicsAppSystems,
+    gameplay::{
+        player::{
+            Player, dialogue::InConversation, navmesh_position::LastValidPlayerNavmeshPosition,
+        },
+        seating::{Seat, SeatOccupant, SitDown, SittingOn, StandUp, is_upright},
+    },
     screens::Screen,
 };
 
@@ -27,6 +34,8 @@ pub(super) fn plugin(app: &mut App) {
     app.register_type::<Agent>();
     app.register_type::<AgentOf>();
     app.register_type::<WantsToFollowPlayer>();
+    app.register_type::<IdleTime>();
+    app.register_type::<WantsToSit>();
     app.add_systems(
         RunFixedMainLoop,
         (sync_agent_velocity, set_controller_velocity)
@@ -39,7 +48,14 @@ pub(super) fn plugin(app: &mut App) {
         RunFixedMainLoop,
         update_agent_target.in_set(PrePhysicsAppSystems::UpdateNavmeshTargets),
     );
+    app.add_systems(
+        Update,
+        sit_when_idle
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
     app.add_observer(setup_npc_agent);
+    app.add_observer(stop_agent_in_conversation);
 }
 
 /// Setup the NPC agent. An "agent" is what `bevy_landmass` can move around.
@@ -51,6 +67,7 @@ fn setup_npc_agent(
     archipelago: Single<Entity, With<Archipelago3d>>,
 ) {
     let npc = trigger.target();
+    commands.entity(npc).insert(IdleTime::default());
     commands.spawn((
         Name::new("NPC Agent"),
         Transform::from_translation(Vec3::new(0.0, -NPC_FLOAT_HEIGHT, 0.0)),
@@ -73,18 +90,144 @@ fn setup_npc_agent(
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
-struct WantsToFollowPlayer;
+pub(crate) struct WantsToFollowPlayer;
+
+/// How long the NPC stands around before looking for a seat, in seconds.
+const IDLE_TIME_BEFORE_SITTING: f32 = 5.0;
+/// How close to the player a seat has to be for the NPC to sit on it.
+const MAX_SEAT_DISTANCE_TO_PLAYER: f32 = 6.0;
+/// How close the NPC has to get to a seat to sit down. This has to be larger than the agent's [`TargetReachedCondition`].
+const SIT_DISTANCE: f32 = 2.5;
+/// How far the player can walk away before the NPC gets up to follow them again.
+const STAND_UP_DISTANCE: f32 = 8.0;
+
+/// How long the NPC has been standing around, in seconds.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub(crate) struct IdleTime(f32);
+
+/// The NPC is walking towards a seat to sit on it.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub(crate) struct WantsToSit(Entity);
 
 #[cfg_attr(feature = "hot_patch", hot)]
 fn update_agent_target(
-    mut agents: Query<&mut AgentTarget3d, With<WantsToFollowPlayer>>,
+    mut agents: Query<(&mut AgentTarget3d, &AgentOf), With<WantsToFollowPlayer>>,
+    q_npc: Query<(Has<InConversation>, Has<SittingOn>, Option<&WantsToSit>)>,
+    q_seat: Query<&GlobalTransform, With<Seat>>,
     player_position: Single<&LastValidPlayerNavmeshPosition>,
 ) {
     let Some(player_position) = player_position.0 else {
         return;
     };
-    for mut target in &mut agents {
-        *target = AgentTarget3d::Point(player_position);
+    for (mut target, agent_of) in &mut agents {
+        let Ok((in_conversation, sitting, wants_to_sit)) = q_npc.get(**agent_of) else {
+            continue;
+        };
+        if in_conversation {
+            continue;
+        }
+        let seat = wants_to_sit.and_then(|wants_to_sit| q_seat.get(wants_to_sit.0).ok());
+        *target = if sitting {
+            AgentTarget3d::None
+        } else if let Some(seat) = seat {
+            AgentTarget3d::Point(seat.translation())
+        } else {
+            AgentTarget3d::Point(player_position)
+        };
+    }
+}
+
+/// When the NPC has been waiting next to the player for a while, it walks to a free chair nearby and sits down.
+/// It gets back up once the player walks away.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn sit_when_idle(
+    time: Res<Time>,
+    mut q_npc: Query<(
+        Entity,
+        &GlobalTransform,
+        &LinearVelocity,
+        &mut IdleTime,
+        Option<&WantsToSit>,
+        Has<SittingOn>,
+        Has<InConversation>,
+    )>,
+    q_free_seat: Query<(Entity, &GlobalTransform), (With<Seat>, Without<SeatOccupant>)>,
+    player: Single<&GlobalTransform, With<Player>>,
+    mut commands: Commands,
+) {
+    let player_position = player.translation();
+    let near_player = |seat: &GlobalTransform| {
+        is_upright(seat)
+            && seat.translation().distance(player_position) < MAX_SEAT_DISTANCE_TO_PLAYER
+    };
+    for (npc, transform, velocity, mut idle_time, wants_to_sit, sitting, in_conversation) in
+        &mut q_npc
+    {
+        if sitting {
+            if !in_conversation
+                && transform.translation().distance(player_position) > STAND_UP_DISTANCE
+            {
+                commands.trigger_targets(StandUp, npc);
+            }
+            continue;
+        }
+        if let Some(wants_to_sit) = wants_to_sit {
+            match q_free_seat.get(wants_to_sit.0) {
+                Ok((seat, seat_transform)) if near_player(seat_transform) => {
+                    let distance = transform
+                        .translation()
+                        .xz()
+                        .distance(seat_transform.translation().xz());
+                    if distance < SIT_DISTANCE {
+                        commands.trigger_targets(SitDown { seat }, npc);
+                        commands.entity(npc).remove::<WantsToSit>();
+                    }
+                }
+                // Someone else took the seat, it fell over, or the player left.
+                _ => {
+                    commands.entity(npc).remove::<WantsToSit>();
+                }
+            }
+            continue;
+        }
+        if in_conversation || velocity.length() > 0.1 {
+            idle_time.0 = 0.0;
+            continue;
+        }
+        idle_time.0 += time.delta_secs();
+        if idle_time.0 < IDLE_TIME_BEFORE_SITTING {
+            continue;
+        }
+        idle_time.0 = 0.0;
+        let closest_seat = q_free_seat
+            .iter()
+            .filter(|(_, seat_transform)| near_player(seat_transform))
+            .min_by(|(_, a), (_, b)| {
+                let a = a.translation().distance_squared(transform.translation());
+                let b = b.translation().distance_squared(transform.translation());
+                a.total_cmp(&b)
+            });
+        if let Some((seat, _)) = closest_seat {
+            commands.entity(npc).insert(WantsToSit(seat));
+        }
+    }
+}
+
+/// Stop walking when the player starts talking to us.
+/// [`update_agent_target`] picks the target up again once the conversation is over.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stop_agent_in_conversation(
+    trigger: Trigger<OnAdd, InConversation>,
+    q_agent: Query<&Agent>,
+    mut q_agent_target: Query<&mut AgentTarget3d>,
+) {
+    let Ok(agent) = q_agent.get(trigger.target()) else {
+        return;
+    };
+    if let Ok(mut target) = q_agent_target.get_mut(**agent) {
+        *target = AgentTarget3d::None;
     }
 }
 
@@ -96,20 +239,32 @@ struct AgentOf(Entity);
 #[derive(Component, Deref, Debug, Reflect)]
 #[reflect(Component)]
 #[relationship_target(relationship = AgentOf)]
-struct Agent(Entity);
+pub(crate) struct Agent(Entity);
 
 /// Use the desired velocity as the agent's velocity.
+/// NPCs in a conversation stand still and turn towards the player.
 #[cfg_attr(feature = "hot_patch", hot)]
 fn set_controller_velocity(
-    mut agent_query: Query<(&mut TnuaController, &Agent)>,
+    mut agent_query: Query<(
+        &mut TnuaController,
+        &Agent,
+        &GlobalTransform,
+        Has<InConversation>,
+    )>,
     desired_velocity_query: Query<&LandmassAgentDesiredVelocity>,
+    player: Single<&GlobalTransform, With<Player>>,
 ) {
-    for (mut controller, agent) in &mut agent_query {
-        let Ok(desired_velocity) = desired_velocity_query.get(**agent) else {
-            continue;
+    for (mut controller, agent, transform, in_conversation) in &mut agent_query {
+        let (velocity, forward) = if in_conversation {
+            let to_player = (player.translation() - transform.translation()).with_y(0.0);
+            (Vec3::ZERO, Dir3::try_from(to_player).ok())
+        } else {
+            let Ok(desired_velocity) = desired_velocity_query.get(**agent) else {
+                continue;
+            };
+            let velocity = desired_velocity.velocity();
+            (velocity, Dir3::try_from(velocity).ok())
         };
-        let velocity = desired_velocity.velocity();
-        let forward = Dir3::try_from(velocity).ok();
         controller.basis(TnuaBuiltinWalk {
             desired_velocity: velocity,
             desired_forward: forward,
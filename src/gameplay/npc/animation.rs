@@ -7,7 +7,11 @@ use bevy::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_tnua::{TnuaAnimatingState, TnuaAnimatingStateDirective, prelude::*};
 
-use crate::{PostPhysicsAppSystems, gameplay::animation::AnimationPlayers, screens::Screen};
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::{animation::AnimationPlayers, player::dialogue::InConversation},
+    screens::Screen,
+};
 
 use super::assets::NpcAssets;
 
@@ -78,6 +82,7 @@ fn play_animations(
         &mut TnuaAnimatingState<NpcAnimationState>,
         &TnuaController,
         &AnimationPlayers,
+        Has<InConversation>,
     )>,
     mut q_animation: Query<(
         &NpcAnimations,
@@ -85,7 +90,7 @@ fn play_animations(
         &mut AnimationTransitions,
     )>,
 ) {
-    for (mut animating_state, controller, anim_players) in &mut query {
+    for (mut animating_state, controller, anim_players, in_conversation) in &mut query {
         let mut iter = q_animation.iter_many_mut(anim_players.iter());
         while let Some((animations, mut anim_player, mut transitions)) = iter.fetch_next() {
             match animating_state.update_by_discriminant({
@@ -95,6 +100,9 @@ fn play_animations(
                 let speed = basis_state.running_velocity.length();
                 if controller.is_airborne().unwrap() {
                     NpcAnimationState::Airborne
+                } else if in_conversation {
+                    // Don't shuffle around while turning towards the player.
+                    NpcAnimationState::Standing
                 } else if speed > 4.5 {
                     NpcAnimationState::Running(speed)
                 } else if speed > 0.01 {
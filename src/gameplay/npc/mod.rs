@@ -9,16 +9,19 @@ use bevy_tnua::{TnuaAnimatingState, prelude::*};
 use bevy_tnua_avian3d::TnuaAvian3dSensorShape;
 use bevy_trenchbroom::prelude::*;
 
-use crate::third_party::{
-    avian3d::CollisionLayer, bevy_trenchbroom::LoadTrenchbroomModel as _,
-    bevy_yarnspinner::YarnNode,
+use crate::{
+    gameplay::{interaction::nameplate::Nameplate, seating::Sitter, targets::TargetName},
+    third_party::{
+        avian3d::CollisionLayer, bevy_trenchbroom::LoadTrenchbroomModel as _,
+        bevy_yarnspinner::YarnNode,
+    },
 };
 
 use super::animation::AnimationPlayerAncestor;
 pub(crate) mod ai;
 mod animation;
 mod assets;
-mod sound;
+pub(crate) mod sound;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins((ai::plugin, animation::plugin, assets::plugin, sound::plugin));
@@ -28,7 +31,7 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(PointClass, Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, TargetName)]
 #[model("models/fox/Fox.gltf")]
 // In Wasm, TrenchBroom classes are not automatically registered.
 // So, we need to manually register the class in `src/third_party/bevy_trenchbroom/mod.rs`.
@@ -57,6 +60,10 @@ fn on_add(trigger: Trigger<OnAdd, Npc>, mut commands: Commands, assets: Res<Asse
             CollisionLayers::new(CollisionLayer::Character, LayerMask::ALL),
             // The Yarn Node is what we use to trigger dialogue.
             YarnNode::new("Npc"),
+            Nameplate::new("The Follower", Vec3::Y * (NPC_HALF_HEIGHT + 0.3)),
+            Sitter {
+                hip_height: NPC_FLOAT_HEIGHT,
+            },
         ))
         .with_child((
             Name::new("Npc Model"),
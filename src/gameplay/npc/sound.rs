@@ -1,4 +1,5 @@
 //! NPC sound handling. The only sound is a step sound that plays when the NPC is walking.
+//! Voice lines reuse the same spatial setup, see [`spatial_playback_settings`].
 
 use super::{Npc, assets::NpcAssets};
 use crate::{PostPhysicsAppSystems, audio::SoundEffect, screens::Screen};
@@ -56,11 +57,16 @@ fn play_step_sound(
     commands.entity(entity).with_child((
         Transform::default(),
         AudioPlayer(sound_effect),
-        PlaybackSettings::DESPAWN
-            .with_spatial(true)
+        spatial_playback_settings()
             .with_speed(1.5)
-            .with_volume(Volume::Linear(1.6))
-            .with_spatial_scale(SpatialScale::new(1.0 / 3.6)),
+            .with_volume(Volume::Linear(1.6)),
         SoundEffect,
     ));
 }
+
+/// Settings for a one-shot sound that is spawned as a child of an NPC, such as a step or a voice line.
+pub(crate) fn spatial_playback_settings() -> PlaybackSettings {
+    PlaybackSettings::DESPAWN
+        .with_spatial(true)
+        .with_spatial_scale(SpatialScale::new(1.0 / 3.6))
+}
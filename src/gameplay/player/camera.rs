@@ -5,7 +5,7 @@
 
 use std::{f32::consts::FRAC_PI_2, iter};
 
-use avian_pickup::prelude::*;
+use avian_pickup::{prelude::*, prop::HeldProp};
 use avian3d::prelude::*;
 use bevy::{
     core_pipeline::{Skybox, bloom::Bloom, tonemapping::Tonemapping},
@@ -37,7 +37,9 @@ use crate::{
     third_party::{avian3d::CollisionLayer, bevy_trenchbroom::LoadTrenchbroomModel as _},
 };
 
-use super::{PLAYER_FLOAT_HEIGHT, Player, default_input::Rotate};
+use super::{
+    PLAYER_FLOAT_HEIGHT, Player, default_input::Rotate, pickup::rotation::HeldPropControl,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<CameraSensitivity>();
@@ -75,7 +77,7 @@ pub(crate) struct PlayerCamera;
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 #[require(Transform, Visibility)]
-struct WorldModelCamera;
+pub(crate) struct WorldModelCamera;
 
 #[cfg_attr(feature = "hot_patch", hot)]
 fn spawn_view_model(
@@ -238,13 +240,19 @@ fn configure_player_view_model(
 #[cfg_attr(feature = "hot_patch", hot)]
 fn rotate_camera_yaw_and_pitch(
     trigger: Trigger<Fired<Rotate>>,
-    mut transform: Single<&mut Transform, With<PlayerCamera>>,
+    camera: Single<(&mut Transform, &HeldPropControl), With<PlayerCamera>>,
+    q_held_prop: Query<(), With<HeldProp>>,
     sensitivity: Res<CameraSensitivity>,
     window: Single<&Window>,
 ) {
     if window.cursor_options.grab_mode == CursorGrabMode::None {
         return;
     }
+    let (mut transform, held_prop_control) = camera.into_inner();
+    // The input rotates the held prop instead, see `pickup::rotation`.
+    if held_prop_control.rotating && !q_held_prop.is_empty() {
+        return;
+    }
 
     let delta = trigger.value;
 
@@ -50,6 +50,22 @@ pub(crate) struct PickupProp;
 #[input_action(output = bool)]
 pub(crate) struct DropProp;
 
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(crate) struct RotateHeldProp;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(crate) struct SnapHeldPropRotation;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+pub(crate) struct AdjustHoldDistance;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(crate) struct OpenInventory;
+
 #[derive(Debug, InputContext, Default)]
 pub(crate) struct DefaultInputContext;
 
@@ -99,6 +115,27 @@ fn default_binding(
     actions
         .bind::<DropProp>()
         .to((MouseButton::Right, GamepadButton::East));
+
+    actions
+        .bind::<RotateHeldProp>()
+        .to((KeyCode::KeyR, GamepadButton::LeftTrigger));
+
+    actions
+        .bind::<SnapHeldPropRotation>()
+        .to((KeyCode::ShiftLeft, GamepadButton::RightTrigger));
+
+    // The scroll wheel scrolls along the Y axis, but the action only reads the X axis.
+    actions.bind::<AdjustHoldDistance>().to((
+        Input::mouse_wheel().with_modifiers(SwizzleAxis::YXZ),
+        Bidirectional {
+            positive: GamepadButton::DPadUp,
+            negative: GamepadButton::DPadDown,
+        },
+    ));
+
+    actions
+        .bind::<OpenInventory>()
+        .to((KeyCode::KeyI, GamepadButton::Select));
 }
 
 #[derive(Resource, Default, Reflect, Deref, DerefMut)]
@@ -0,0 +1,88 @@
+//! A log of every line that was delivered and every option the player chose, so that players who click through quickly
+//! can read up on what they missed. A new session starts each time the dialogue runner starts a dialogue.
+//! The history is part of the save game.
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::{
+    events::{DialogueStartEvent, PresentLineEvent},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{PostPhysicsAppSystems, save::SaveGame, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<DialogueHistory>();
+    app.add_systems(OnEnter(Screen::Gameplay), restore_dialogue_history);
+    app.add_systems(
+        Update,
+        (
+            start_session.run_if(on_event::<DialogueStartEvent>),
+            record_lines.run_if(on_event::<PresentLineEvent>),
+            save_dialogue_history.run_if(resource_changed::<DialogueHistory>),
+        )
+            .chain()
+            .after(YarnSpinnerSystemSet)
+            .in_set(PostPhysicsAppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct DialogueHistory {
+    pub(crate) sessions: Vec<DialogueSession>,
+}
+
+impl DialogueHistory {
+    /// Add an entry to the current session.
+    pub(crate) fn record(&mut self, entry: DialogueHistoryEntry) {
+        if let Some(session) = self.sessions.last_mut() {
+            session.entries.push(entry);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct DialogueSession {
+    pub(crate) entries: Vec<DialogueHistoryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum DialogueHistoryEntry {
+    Line {
+        speaker: Option<String>,
+        text: String,
+    },
+    /// The text of the option the player chose.
+    Choice(String),
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn restore_dialogue_history(mut history: ResMut<DialogueHistory>, save_game: Res<SaveGame>) {
+    *history = save_game.dialogue_history.clone();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn start_session(mut history: ResMut<DialogueHistory>) {
+    history.sessions.push(default());
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn record_lines(
+    mut line_events: EventReader<PresentLineEvent>,
+    mut history: ResMut<DialogueHistory>,
+) {
+    for event in line_events.read() {
+        history.record(DialogueHistoryEntry::Line {
+            speaker: event.line.character_name().map(str::to_string),
+            text: event.line.text_without_character_name(),
+        });
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn save_dialogue_history(history: Res<DialogueHistory>, mut save_game: ResMut<SaveGame>) {
+    save_game.dialogue_history = history.clone();
+}
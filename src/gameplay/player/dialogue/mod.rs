@@ -1,56 +1,33 @@
-//! Player dialogue handling. This module starts the Yarn Spinner dialogue when the player starts interacting with an NPC.
+//! Player dialogue handling. This module starts the Yarn Spinner dialogue when the player uses something with a [`YarnNode`].
 
 use std::any::Any;
 
-use avian3d::prelude::{SpatialQuery, SpatialQueryFilter};
 use bevy::prelude::*;
-use bevy_enhanced_input::events::Started;
 #[cfg(feature = "hot_patch")]
 use bevy_simple_subsecond_system::hot;
 use bevy_yarnspinner::{events::DialogueCompleteEvent, prelude::*};
 
 use crate::{
     PostPhysicsAppSystems,
-    gameplay::crosshair::CrosshairState,
-    screens::Screen,
-    third_party::{
-        avian3d::CollisionLayer,
-        bevy_yarnspinner::{YarnNode, is_dialogue_running},
+    gameplay::{
+        crosshair::CrosshairState,
+        interaction::{Interactable, Interacted, InteractionAction},
     },
+    screens::Screen,
+    third_party::bevy_yarnspinner::YarnNode,
 };
 
+pub(crate) mod history;
 mod ui;
+pub(crate) mod view;
+pub(crate) mod yarn_commands;
+pub(crate) mod yarn_functions;
 
-use super::{
-    Player,
-    camera::PlayerCamera,
-    default_input::{BlocksInput, Interact},
-    pickup::is_holding_prop,
-};
+use super::default_input::BlocksInput;
 
 pub(super) fn plugin(app: &mut App) {
-    app.register_type::<InteractionPrompt>();
-
-    app.configure_sets(
-        Update,
-        (
-            DialogueSystems::UpdateOpportunity,
-            DialogueSystems::UpdateUi,
-        )
-            .chain()
-            .in_set(PostPhysicsAppSystems::ChangeUi),
-    );
+    app.register_type::<InConversation>();
 
-    app.add_systems(
-        Update,
-        check_for_dialogue_opportunity
-            .in_set(DialogueSystems::UpdateOpportunity)
-            .run_if(
-                in_state(Screen::Gameplay)
-                    .and(not(is_dialogue_running))
-                    .and(not(is_holding_prop)),
-            ),
-    );
     app.add_systems(
         Update,
         restore_input_context
@@ -58,59 +35,53 @@ pub(super) fn plugin(app: &mut App) {
             .in_set(PostPhysicsAppSystems::Update),
     );
 
+    app.add_observer(make_yarn_node_interactable);
     app.add_observer(interact_with_dialogue);
 
-    app.add_plugins(ui::plugin);
-}
-
-#[derive(Debug, SystemSet, Hash, Eq, PartialEq, Clone, Copy)]
-pub(super) enum DialogueSystems {
-    UpdateOpportunity,
-    UpdateUi,
+    app.add_plugins((
+        history::plugin,
+        ui::plugin,
+        view::plugin,
+        yarn_commands::plugin,
+        yarn_functions::plugin,
+    ));
 }
 
 #[cfg_attr(feature = "hot_patch", hot)]
-fn check_for_dialogue_opportunity(
-    player: Single<&GlobalTransform, With<PlayerCamera>>,
-    player_collider: Single<Entity, With<Player>>,
-    mut interaction_prompt: Single<&mut InteractionPrompt>,
+fn make_yarn_node_interactable(
+    trigger: Trigger<OnAdd, YarnNode>,
     q_yarn_node: Query<&YarnNode>,
-    spatial_query: SpatialQuery,
+    mut commands: Commands,
 ) {
-    let camera_transform = player.compute_transform();
-    const MAX_INTERACTION_DISTANCE: f32 = 3.0;
-    let hit = spatial_query.cast_ray(
-        camera_transform.translation,
-        camera_transform.forward(),
-        MAX_INTERACTION_DISTANCE,
-        true,
-        &SpatialQueryFilter::from_mask(CollisionLayer::Character)
-            .with_excluded_entities([*player_collider]),
-    );
-    let node = hit
-        .and_then(|hit| q_yarn_node.get(hit.entity).ok())
-        .cloned();
-    if interaction_prompt.0 != node {
-        interaction_prompt.0 = node;
-    }
+    let yarn_node = q_yarn_node.get(trigger.target()).unwrap();
+    commands.entity(trigger.target()).insert(Interactable::new(
+        yarn_node.prompt.clone(),
+        InteractionAction::Talk(yarn_node.yarn_node.clone()),
+    ));
 }
 
-#[derive(Component, Default, Reflect)]
-#[reflect(Component, Default)]
-struct InteractionPrompt(Option<YarnNode>);
+/// Marks the character the player is currently talking to.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub(crate) struct InConversation;
 
 #[cfg_attr(feature = "hot_patch", hot)]
 fn interact_with_dialogue(
-    _trigger: Trigger<Started<Interact>>,
-    mut interaction_prompt: Single<&mut InteractionPrompt>,
+    trigger: Trigger<Interacted>,
     mut dialogue_runner: Single<&mut DialogueRunner>,
     mut crosshair: Single<&mut CrosshairState>,
     mut blocks_input: ResMut<BlocksInput>,
+    mut commands: Commands,
 ) {
-    let Some(node) = interaction_prompt.0.take() else {
+    let InteractionAction::Talk(node) = &trigger.event().0 else {
         return;
     };
-    dialogue_runner.start_node(&node.yarn_node);
+    if !dialogue_runner.node_exists(node) {
+        // Already reported by `validate_yarn_nodes`.
+        return;
+    }
+    commands.entity(trigger.target()).insert(InConversation);
+    dialogue_runner.start_node(node);
     blocks_input.insert(interact_with_dialogue.type_id());
     crosshair
         .wants_free_cursor
@@ -121,7 +92,12 @@ fn interact_with_dialogue(
 fn restore_input_context(
     mut crosshair: Single<&mut CrosshairState>,
     mut blocks_input: ResMut<BlocksInput>,
+    q_speaker: Query<Entity, With<InConversation>>,
+    mut commands: Commands,
 ) {
+    for speaker in &q_speaker {
+        commands.entity(speaker).remove::<InConversation>();
+    }
     blocks_input.remove(&interact_with_dialogue.type_id());
     crosshair
         .wants_free_cursor
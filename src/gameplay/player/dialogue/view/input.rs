@@ -0,0 +1,173 @@
+//! Input for the dialogue view. The dialogue has its own input context that only exists while a dialogue is running,
+//! so that it doesn't interfere with the player's input.
+
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::{
+    events::{DialogueCompleteEvent, DialogueStartEvent},
+    prelude::*,
+};
+
+use crate::{gameplay::player::dialogue::history::DialogueHistory, menus::Menu, screens::Screen};
+
+use super::{
+    DialogueView, DialogueViewSystems,
+    option_selection::{PresentedOptions, choose_option},
+    typewriter::Typewriter,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_input_context::<DialogueInputContext>();
+    app.add_observer(dialogue_binding);
+    app.add_observer(advance_dialogue);
+    app.add_observer(open_dialogue_log);
+
+    app.add_systems(
+        Update,
+        (
+            enable_dialogue_input.run_if(on_event::<DialogueStartEvent>),
+            disable_dialogue_input.run_if(on_event::<DialogueCompleteEvent>),
+        )
+            .in_set(DialogueViewSystems::Present)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Debug, InputContext, Default)]
+pub(super) struct DialogueInputContext;
+
+/// Skip the typewriter, continue to the next line or choose the selected option.
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct AdvanceDialogue;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct OpenDialogueLog;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(super) struct SelectNextOption;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(super) struct SelectPreviousOption;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn dialogue_binding(
+    trigger: Trigger<Binding<DialogueInputContext>>,
+    mut q_actions: Query<&mut Actions<DialogueInputContext>>,
+) {
+    let mut actions = q_actions.get_mut(trigger.target()).unwrap();
+    // Clicking is handled by `advance_dialogue_on_click` so that clicking an option doesn't also advance the dialogue.
+    actions.bind::<AdvanceDialogue>().to((
+        KeyCode::Space,
+        KeyCode::Enter,
+        KeyCode::NumpadEnter,
+        GamepadButton::South,
+    ));
+    actions
+        .bind::<OpenDialogueLog>()
+        .to((KeyCode::KeyL, GamepadButton::North));
+    actions.bind::<SelectNextOption>().to((
+        KeyCode::ArrowDown,
+        KeyCode::KeyS,
+        GamepadButton::DPadDown,
+    ));
+    actions.bind::<SelectPreviousOption>().to((
+        KeyCode::ArrowUp,
+        KeyCode::KeyW,
+        GamepadButton::DPadUp,
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn enable_dialogue_input(view: Single<Entity, With<DialogueView>>, mut commands: Commands) {
+    commands
+        .entity(*view)
+        .insert(Actions::<DialogueInputContext>::default());
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn disable_dialogue_input(view: Single<Entity, With<DialogueView>>, mut commands: Commands) {
+    commands
+        .entity(*view)
+        .remove::<Actions<DialogueInputContext>>();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn advance_dialogue(
+    _trigger: Trigger<Started<AdvanceDialogue>>,
+    menu: Res<State<Menu>>,
+    mut typewriter: ResMut<Typewriter>,
+    mut options: ResMut<PresentedOptions>,
+    mut history: ResMut<DialogueHistory>,
+    mut dialogue_runner: Single<&mut DialogueRunner>,
+) {
+    // E.g. the dialogue log is open.
+    if menu.get() != &Menu::None {
+        return;
+    }
+    advance(
+        &mut typewriter,
+        &mut options,
+        &mut history,
+        &mut dialogue_runner,
+    );
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+pub(super) fn advance_dialogue_on_click(
+    _trigger: Trigger<Pointer<Click>>,
+    menu: Res<State<Menu>>,
+    mut typewriter: ResMut<Typewriter>,
+    mut options: ResMut<PresentedOptions>,
+    mut history: ResMut<DialogueHistory>,
+    mut dialogue_runner: Single<&mut DialogueRunner>,
+) {
+    // Options are chosen by clicking on them directly.
+    if menu.get() != &Menu::None || (!options.is_empty() && typewriter.is_finished()) {
+        return;
+    }
+    advance(
+        &mut typewriter,
+        &mut options,
+        &mut history,
+        &mut dialogue_runner,
+    );
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_dialogue_log(
+    _trigger: Trigger<Started<OpenDialogueLog>>,
+    menu: Res<State<Menu>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    if menu.get() == &Menu::None {
+        next_menu.set(Menu::DialogueLog);
+    }
+}
+
+fn advance(
+    typewriter: &mut Typewriter,
+    options: &mut PresentedOptions,
+    history: &mut DialogueHistory,
+    dialogue_runner: &mut DialogueRunner,
+) {
+    if !dialogue_runner.is_running() || !typewriter.has_started() {
+        return;
+    }
+    if !typewriter.is_finished() {
+        typewriter.skip();
+        return;
+    }
+    if let Some(option) = options.selected() {
+        choose_option(dialogue_runner, options, history, option);
+        return;
+    }
+    if !dialogue_runner.is_waiting_for_option_selection() {
+        dialogue_runner.continue_in_next_update();
+    }
+}
@@ -0,0 +1,214 @@
+//! Our own dialogue view for Yarn Spinner. It shows a dialogue box at the bottom of the screen with a name plate,
+//! an optional portrait and the current line, which is revealed by a typewriter effect. Options are listed below the line.
+//!
+//! A line can show a portrait by tagging it with `#portrait:<name>`, which loads `images/portraits/<name>.png`.
+//! Voice lines are played by the [`voice`] module.
+
+use bevy::{prelude::*, ui::Val::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::{
+    events::{DialogueCompleteEvent, DialogueStartEvent, PresentLineEvent},
+    prelude::*,
+};
+
+use crate::{
+    PostPhysicsAppSystems,
+    screens::Screen,
+    theme::{palette::BUTTON_PRESSED_BACKGROUND, prelude::*},
+};
+
+mod input;
+mod option_selection;
+mod typewriter;
+pub(crate) mod voice;
+
+use typewriter::Typewriter;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<DialogueView>();
+    app.register_type::<NamePlate>();
+    app.register_type::<Portrait>();
+    app.register_type::<DialogueText>();
+
+    app.configure_sets(
+        Update,
+        (DialogueViewSystems::Present, DialogueViewSystems::Reveal)
+            .chain()
+            .after(YarnSpinnerSystemSet)
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
+
+    app.add_plugins((
+        input::plugin,
+        option_selection::plugin,
+        typewriter::plugin,
+        voice::plugin,
+    ));
+
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_dialogue_view);
+    app.add_systems(
+        Update,
+        (
+            show_dialogue_view.run_if(on_event::<DialogueStartEvent>),
+            present_line,
+            hide_dialogue_view.run_if(on_event::<DialogueCompleteEvent>),
+        )
+            .chain()
+            .in_set(DialogueViewSystems::Present)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Debug, SystemSet, Hash, Eq, PartialEq, Clone, Copy)]
+enum DialogueViewSystems {
+    /// React to the events sent by the dialogue runner.
+    Present,
+    /// Reveal the presented content over time.
+    Reveal,
+}
+
+/// The root of the dialogue view.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct DialogueView;
+
+/// The plate above the dialogue text that shows who is speaking.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct NamePlate;
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct Portrait;
+
+/// The text of the current line.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct DialogueText;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_dialogue_view(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Dialogue View"),
+            DialogueView,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Percent(100.0),
+                bottom: Px(30.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Visibility::Hidden,
+            StateScoped(Screen::Gameplay),
+            Pickable::IGNORE,
+            children![(
+                widget::panel(
+                    "Dialogue Box",
+                    Node {
+                        width: Percent(70.0),
+                        max_width: Px(1100.0),
+                        column_gap: Px(20.0),
+                        padding: UiRect::all(Px(20.0)),
+                        ..default()
+                    },
+                ),
+                children![
+                    (
+                        Name::new("Portrait"),
+                        Portrait,
+                        ImageNode::default(),
+                        Node {
+                            display: Display::None,
+                            width: Px(128.0),
+                            height: Px(128.0),
+                            flex_shrink: 0.0,
+                            ..default()
+                        },
+                        BorderRadius::all(Px(8.0)),
+                    ),
+                    (
+                        Name::new("Dialogue Content"),
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            flex_grow: 1.0,
+                            row_gap: Px(10.0),
+                            ..default()
+                        },
+                        children![
+                            (
+                                Name::new("Name Plate"),
+                                NamePlate,
+                                Node {
+                                    display: Display::None,
+                                    align_self: AlignSelf::Start,
+                                    padding: UiRect::axes(Px(12.0), Px(4.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(BUTTON_PRESSED_BACKGROUND),
+                                BorderRadius::all(Px(6.0)),
+                                children![(widget::label(""), ScaledFont(20.0), Pickable::IGNORE)],
+                            ),
+                            (
+                                widget::paragraph(""),
+                                DialogueText,
+                                Pickable::IGNORE,
+                                children![typewriter::hidden_text_span()],
+                            ),
+                            option_selection::option_list(),
+                        ],
+                    ),
+                ],
+            )],
+        ))
+        .observe(input::advance_dialogue_on_click);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn show_dialogue_view(mut visibility: Single<&mut Visibility, With<DialogueView>>) {
+    **visibility = Visibility::Inherited;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn hide_dialogue_view(mut visibility: Single<&mut Visibility, With<DialogueView>>) {
+    **visibility = Visibility::Hidden;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn present_line(
+    mut line_events: EventReader<PresentLineEvent>,
+    mut typewriter: ResMut<Typewriter>,
+    name_plate: Single<(&mut Node, &Children), (With<NamePlate>, Without<Portrait>)>,
+    mut q_text: Query<&mut Text>,
+    portrait: Single<(&mut Node, &mut ImageNode), (With<Portrait>, Without<NamePlate>)>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(event) = line_events.read().last() else {
+        return;
+    };
+    let line = &event.line;
+
+    let (mut name_plate_node, name_plate_children) = name_plate.into_inner();
+    if let Some(name) = line.character_name() {
+        name_plate_node.display = Display::Flex;
+        let mut name_text = q_text.get_mut(name_plate_children[0]).unwrap();
+        name_text.0 = name.to_string();
+    } else {
+        name_plate_node.display = Display::None;
+    }
+
+    let (mut portrait_node, mut portrait_image) = portrait.into_inner();
+    let portrait_name = line
+        .metadata
+        .iter()
+        .find_map(|tag| tag.strip_prefix("portrait:"));
+    if let Some(portrait_name) = portrait_name {
+        portrait_node.display = Display::Flex;
+        portrait_image.image = asset_server.load(format!("images/portraits/{portrait_name}.png"));
+    } else {
+        portrait_node.display = Display::None;
+    }
+
+    typewriter.start(line.text_without_character_name());
+}
@@ -0,0 +1,317 @@
+//! Lists the options presented by the dialogue runner once the current line is fully revealed.
+//! Options can be chosen by clicking them, by pressing their number, or by selecting them with the arrow keys
+//! or the gamepad's D-pad and confirming.
+
+use bevy::{prelude::*, ui::Val::*};
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::{
+    events::{DialogueCompleteEvent, PresentLineEvent, PresentOptionsEvent},
+    prelude::*,
+};
+
+use crate::{
+    gameplay::player::dialogue::history::{DialogueHistory, DialogueHistoryEntry},
+    screens::Screen,
+    theme::{
+        palette::{BUTTON_BACKGROUND, BUTTON_HOVERED_BACKGROUND, DISABLED_TEXT},
+        prelude::*,
+    },
+};
+
+use super::{
+    DialogueViewSystems,
+    input::{SelectNextOption, SelectPreviousOption},
+    typewriter::Typewriter,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<PresentedOptions>();
+    app.register_type::<OptionList>();
+    app.register_type::<OptionButton>();
+
+    app.add_systems(
+        Update,
+        (
+            clear_options
+                .run_if(on_event::<PresentLineEvent>.or(on_event::<DialogueCompleteEvent>)),
+            present_options.run_if(on_event::<PresentOptionsEvent>),
+        )
+            .chain()
+            .in_set(DialogueViewSystems::Present)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(
+        Update,
+        (
+            show_options_when_line_is_revealed,
+            choose_option_by_number,
+            highlight_selected_option.run_if(resource_changed::<PresentedOptions>),
+        )
+            .chain()
+            .in_set(DialogueViewSystems::Reveal)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_observer(select_next_option);
+    app.add_observer(select_previous_option);
+}
+
+/// The options the player can currently choose from.
+#[derive(Resource, Debug, Default)]
+pub(super) struct PresentedOptions {
+    options: Vec<PresentedOption>,
+    /// The index of the option selected with the keyboard, gamepad or by hovering over it.
+    selected: Option<usize>,
+}
+
+impl PresentedOptions {
+    pub(super) fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    pub(super) fn selected(&self) -> Option<OptionId> {
+        self.selected.map(|index| self.options[index].id)
+    }
+
+    fn is_available(&self, index: usize) -> bool {
+        self.options
+            .get(index)
+            .is_some_and(|option| option.is_available)
+    }
+
+    /// Move the selection by `step`, skipping unavailable options.
+    fn select_relative(&mut self, step: isize) {
+        let len = self.options.len() as isize;
+        if len == 0 {
+            return;
+        }
+        let mut index = match self.selected {
+            Some(index) => index as isize,
+            // Start right before the first or after the last option.
+            None if step > 0 => -1,
+            None => len,
+        };
+        for _ in 0..len {
+            index = (index + step).rem_euclid(len);
+            if self.is_available(index as usize) {
+                self.selected = Some(index as usize);
+                return;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PresentedOption {
+    id: OptionId,
+    is_available: bool,
+    text: String,
+}
+
+pub(super) fn choose_option(
+    dialogue_runner: &mut DialogueRunner,
+    options: &mut PresentedOptions,
+    history: &mut DialogueHistory,
+    option: OptionId,
+) {
+    let Some(chosen) = options
+        .options
+        .iter()
+        .find(|presented| presented.id == option && presented.is_available)
+    else {
+        return;
+    };
+    if let Err(error) = dialogue_runner.select_option(option) {
+        error!("Failed to select dialogue option: {error}");
+        return;
+    }
+    history.record(DialogueHistoryEntry::Choice(chosen.text.clone()));
+    *options = default();
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct OptionList;
+
+/// A button in the [`OptionList`] that chooses the option with the given index.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct OptionButton(usize);
+
+pub(super) fn option_list() -> impl Bundle {
+    (
+        Name::new("Dialogue Options"),
+        OptionList,
+        Node {
+            display: Display::None,
+            flex_direction: FlexDirection::Column,
+            row_gap: Px(6.0),
+            ..default()
+        },
+    )
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn clear_options(
+    mut options: ResMut<PresentedOptions>,
+    option_list: Single<Entity, With<OptionList>>,
+    mut commands: Commands,
+) {
+    *options = default();
+    commands.entity(*option_list).despawn_related::<Children>();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn present_options(
+    mut option_events: EventReader<PresentOptionsEvent>,
+    mut options: ResMut<PresentedOptions>,
+    option_list: Single<Entity, With<OptionList>>,
+    mut commands: Commands,
+) {
+    let Some(event) = option_events.read().last() else {
+        return;
+    };
+    *options = PresentedOptions {
+        options: event
+            .options
+            .iter()
+            .map(|option| PresentedOption {
+                id: option.id,
+                is_available: option.is_available,
+                text: option.line.text_without_character_name(),
+            })
+            .collect(),
+        selected: None,
+    };
+
+    commands
+        .entity(*option_list)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            for (index, option) in event.options.iter().enumerate() {
+                let text = format!(
+                    "{number}. {text}",
+                    number = index + 1,
+                    text = option.line.text_without_character_name()
+                );
+                if !option.is_available {
+                    parent.spawn(widget::paragraph_with_color(text, DISABLED_TEXT));
+                    continue;
+                }
+                let id = option.id;
+                parent
+                    .spawn((
+                        widget::list_button(
+                            text,
+                            move |mut trigger: Trigger<Pointer<Click>>,
+                                  mut dialogue_runner: Single<&mut DialogueRunner>,
+                                  mut options: ResMut<PresentedOptions>,
+                                  mut history: ResMut<DialogueHistory>| {
+                                // Don't let the click advance the dialogue as well.
+                                trigger.propagate(false);
+                                choose_option(&mut dialogue_runner, &mut options, &mut history, id);
+                            },
+                        ),
+                        OptionButton(index),
+                    ))
+                    .observe(select_hovered_option);
+            }
+        });
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn show_options_when_line_is_revealed(
+    mut option_list: Single<&mut Node, With<OptionList>>,
+    options: Res<PresentedOptions>,
+    typewriter: Res<Typewriter>,
+) {
+    let display = if !options.is_empty() && typewriter.is_finished() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    if option_list.display != display {
+        option_list.display = display;
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn choose_option_by_number(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut options: ResMut<PresentedOptions>,
+    typewriter: Res<Typewriter>,
+    mut dialogue_runner: Single<&mut DialogueRunner>,
+    mut history: ResMut<DialogueHistory>,
+) {
+    const NUMBER_KEYS: [[KeyCode; 2]; 9] = [
+        [KeyCode::Digit1, KeyCode::Numpad1],
+        [KeyCode::Digit2, KeyCode::Numpad2],
+        [KeyCode::Digit3, KeyCode::Numpad3],
+        [KeyCode::Digit4, KeyCode::Numpad4],
+        [KeyCode::Digit5, KeyCode::Numpad5],
+        [KeyCode::Digit6, KeyCode::Numpad6],
+        [KeyCode::Digit7, KeyCode::Numpad7],
+        [KeyCode::Digit8, KeyCode::Numpad8],
+        [KeyCode::Digit9, KeyCode::Numpad9],
+    ];
+    if options.is_empty() || !typewriter.is_finished() {
+        return;
+    }
+    let Some(index) = NUMBER_KEYS
+        .iter()
+        .position(|keys_for_number| keys.any_just_pressed(*keys_for_number))
+    else {
+        return;
+    };
+    if let Some(option) = options.options.get(index).map(|option| option.id) {
+        choose_option(&mut dialogue_runner, &mut options, &mut history, option);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn select_next_option(
+    _trigger: Trigger<Started<SelectNextOption>>,
+    mut options: ResMut<PresentedOptions>,
+) {
+    options.select_relative(1);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn select_previous_option(
+    _trigger: Trigger<Started<SelectPreviousOption>>,
+    mut options: ResMut<PresentedOptions>,
+) {
+    options.select_relative(-1);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn select_hovered_option(
+    trigger: Trigger<Pointer<Over>>,
+    q_option_button: Query<&OptionButton>,
+    mut options: ResMut<PresentedOptions>,
+) {
+    let Ok(option_button) = q_option_button.get(trigger.target()) else {
+        return;
+    };
+    options.selected = Some(option_button.0);
+}
+
+/// Give the selected option the same color as a hovered button.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn highlight_selected_option(
+    q_option_button: Query<(&OptionButton, &Children)>,
+    mut q_background: Query<&mut BackgroundColor, With<Button>>,
+    options: Res<PresentedOptions>,
+) {
+    for (option_button, children) in &q_option_button {
+        let mut iter = q_background.iter_many_mut(children);
+        while let Some(mut background) = iter.fetch_next() {
+            background.0 = if options.selected == Some(option_button.0) {
+                BUTTON_HOVERED_BACKGROUND
+            } else {
+                BUTTON_BACKGROUND
+            };
+        }
+    }
+}
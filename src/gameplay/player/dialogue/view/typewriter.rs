@@ -0,0 +1,102 @@
+//! Reveals the text of a dialogue line character by character.
+//! The part of the line that is not revealed yet is still laid out, but transparent, so that the text doesn't jump around while typing.
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    screens::Screen, theme::prelude::*, third_party::bevy_yarnspinner::is_dialogue_running,
+};
+
+use super::{DialogueText, DialogueViewSystems};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Typewriter>();
+    app.register_type::<Typewriter>();
+    app.register_type::<HiddenText>();
+    app.add_systems(
+        Update,
+        reveal_text
+            .in_set(DialogueViewSystems::Reveal)
+            .run_if(in_state(Screen::Gameplay).and(is_dialogue_running)),
+    );
+}
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub(super) struct Typewriter {
+    text: String,
+    revealed_characters: usize,
+    elapsed_seconds: f32,
+}
+
+impl Typewriter {
+    const CHARACTERS_PER_SECOND: f32 = 45.0;
+
+    pub(super) fn start(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.revealed_characters = 0;
+        self.elapsed_seconds = 0.0;
+    }
+
+    /// Reveal the whole line at once.
+    pub(super) fn skip(&mut self) {
+        self.revealed_characters = self.text.chars().count();
+    }
+
+    pub(super) fn is_finished(&self) -> bool {
+        self.revealed_characters >= self.text.chars().count()
+    }
+
+    /// Whether the current line has been on screen for at least one frame.
+    /// Used to ignore the input that started the dialogue.
+    pub(super) fn has_started(&self) -> bool {
+        self.elapsed_seconds > 0.0
+    }
+
+    fn split_at_revealed(&self) -> (&str, &str) {
+        let index = self
+            .text
+            .char_indices()
+            .nth(self.revealed_characters)
+            .map_or(self.text.len(), |(index, _)| index);
+        self.text.split_at(index)
+    }
+}
+
+/// The part of a dialogue line that is not revealed yet.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct HiddenText;
+
+pub(super) fn hidden_text_span() -> impl Bundle {
+    (
+        Name::new("Hidden Text"),
+        HiddenText,
+        TextSpan::default(),
+        ScaledFont(24.0),
+        TextColor(Color::NONE),
+    )
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn reveal_text(
+    time: Res<Time>,
+    mut typewriter: ResMut<Typewriter>,
+    mut revealed_text: Single<&mut Text, With<DialogueText>>,
+    mut hidden_text: Single<&mut TextSpan, With<HiddenText>>,
+) {
+    typewriter.elapsed_seconds += time.delta_secs();
+    let typed_characters =
+        (typewriter.elapsed_seconds * Typewriter::CHARACTERS_PER_SECOND) as usize;
+    typewriter.revealed_characters = typewriter.revealed_characters.max(typed_characters);
+
+    let (revealed, hidden) = typewriter.split_at_revealed();
+    if revealed_text.0 != revealed {
+        revealed_text.0 = revealed.to_string();
+    }
+    if hidden_text.0 != hidden {
+        hidden_text.0 = hidden.to_string();
+    }
+}
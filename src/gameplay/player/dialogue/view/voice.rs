@@ -0,0 +1,211 @@
+//! Voice-over for dialogue lines. The voice line for a Yarn line is found by its line ID:
+//! a line tagged with `#line:follower_greeting` plays `audio/voice/follower_greeting.ogg`.
+//! Lines without a voice line are only shown as text. The voice lines that exist are listed at compile time by `build.rs`,
+//! so that we only load the ones we have.
+//!
+//! The voice plays from the character the player is talking to. While it plays, music and ambience are ducked.
+
+use std::any::Any;
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::{
+    events::{DialogueCompleteEvent, PresentLineEvent},
+    prelude::*,
+};
+
+use crate::{
+    audio::DuckBackgroundAudio,
+    gameplay::{npc::sound::spatial_playback_settings, player::dialogue::InConversation},
+    menus::Menu,
+    screens::Screen,
+};
+
+use super::{DialogueViewSystems, option_selection::PresentedOptions, typewriter::Typewriter};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<VoiceOverSettings>();
+    app.register_type::<VoiceLine>();
+    app.init_resource::<VoiceOverSettings>();
+    app.init_resource::<CurrentVoiceLine>();
+    app.add_observer(finish_voice_line);
+
+    app.add_systems(
+        Update,
+        (
+            play_voice_line.run_if(on_event::<PresentLineEvent>),
+            stop_voice_line.run_if(on_event::<DialogueCompleteEvent>),
+        )
+            .in_set(DialogueViewSystems::Present)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    app.add_systems(OnExit(Screen::Gameplay), stop_voice_line);
+    app.add_systems(
+        Update,
+        (
+            skip_failed_voice_line,
+            // Don't continue while the player reads the dialogue log.
+            auto_advance_after_voice_line.run_if(in_state(Menu::None)),
+        )
+            .chain()
+            .in_set(DialogueViewSystems::Reveal)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+    // Not restricted to gameplay so that the ducking also ends when leaving it.
+    app.add_systems(
+        Update,
+        duck_background_audio_during_voice_line.in_set(DialogueViewSystems::Reveal),
+    );
+}
+
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub(crate) struct VoiceOverSettings {
+    /// Continue to the next line as soon as the voice line has finished playing.
+    pub(crate) auto_advance: bool,
+}
+
+/// A voice line that is currently playing.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct VoiceLine {
+    line_id: String,
+}
+
+#[derive(Resource, Debug, Default, PartialEq, Eq)]
+enum CurrentVoiceLine {
+    /// The current line has no voice line.
+    #[default]
+    None,
+    Playing(Entity),
+    Finished,
+}
+
+/// The IDs of the lines that have a voice line.
+const VOICE_LINES: &[&str] = include!(concat!(env!("OUT_DIR"), "/voice_lines.rs"));
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn play_voice_line(
+    mut line_events: EventReader<PresentLineEvent>,
+    q_voice_line: Query<Entity, With<VoiceLine>>,
+    speaker: Option<Single<Entity, With<InConversation>>>,
+    mut current_voice_line: ResMut<CurrentVoiceLine>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let Some(event) = line_events.read().last() else {
+        return;
+    };
+    for voice_line in &q_voice_line {
+        commands.entity(voice_line).despawn();
+    }
+
+    let line_id = event.line.id.0.trim_start_matches("line:").to_string();
+    if !VOICE_LINES.contains(&line_id.as_str()) {
+        *current_voice_line = CurrentVoiceLine::None;
+        return;
+    }
+    let audio = AudioPlayer(asset_server.load(format!("audio/voice/{line_id}.ogg")));
+    let voice_line = VoiceLine { line_id };
+    let entity = if let Some(speaker) = speaker {
+        commands
+            .spawn((
+                Name::new("Voice Line"),
+                voice_line,
+                audio,
+                spatial_playback_settings(),
+                Transform::default(),
+                ChildOf(*speaker),
+            ))
+            .id()
+    } else {
+        commands
+            .spawn((
+                Name::new("Voice Line"),
+                voice_line,
+                audio,
+                PlaybackSettings::DESPAWN,
+                StateScoped(Screen::Gameplay),
+            ))
+            .id()
+    };
+    *current_voice_line = CurrentVoiceLine::Playing(entity);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stop_voice_line(
+    q_voice_line: Query<Entity, With<VoiceLine>>,
+    mut current_voice_line: ResMut<CurrentVoiceLine>,
+    mut commands: Commands,
+) {
+    *current_voice_line = CurrentVoiceLine::None;
+    for voice_line in &q_voice_line {
+        commands.entity(voice_line).despawn();
+    }
+}
+
+/// Voice lines despawn themselves when they finish playing.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn finish_voice_line(
+    trigger: Trigger<OnRemove, VoiceLine>,
+    mut current_voice_line: ResMut<CurrentVoiceLine>,
+) {
+    // Voice lines that were cut off by the next line don't count.
+    if *current_voice_line == CurrentVoiceLine::Playing(trigger.target()) {
+        *current_voice_line = CurrentVoiceLine::Finished;
+    }
+}
+
+/// A voice line that fails to load never finishes playing, so we show its line as text only.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn skip_failed_voice_line(
+    q_voice_line: Query<(Entity, &AudioPlayer), With<VoiceLine>>,
+    mut current_voice_line: ResMut<CurrentVoiceLine>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    for (entity, audio) in &q_voice_line {
+        if !asset_server.load_state(&audio.0).is_failed() {
+            continue;
+        }
+        if *current_voice_line == CurrentVoiceLine::Playing(entity) {
+            *current_voice_line = CurrentVoiceLine::None;
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn duck_background_audio_during_voice_line(
+    current_voice_line: Res<CurrentVoiceLine>,
+    mut duck: ResMut<DuckBackgroundAudio>,
+) {
+    let system_id = duck_background_audio_during_voice_line.type_id();
+    let is_playing = matches!(*current_voice_line, CurrentVoiceLine::Playing(_));
+    if is_playing && !duck.contains(&system_id) {
+        duck.insert(system_id);
+    } else if !is_playing && duck.contains(&system_id) {
+        duck.remove(&system_id);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn auto_advance_after_voice_line(
+    settings: Res<VoiceOverSettings>,
+    mut current_voice_line: ResMut<CurrentVoiceLine>,
+    typewriter: Res<Typewriter>,
+    options: Res<PresentedOptions>,
+    mut dialogue_runner: Single<&mut DialogueRunner>,
+) {
+    if !settings.auto_advance
+        || *current_voice_line != CurrentVoiceLine::Finished
+        || !typewriter.is_finished()
+        || !options.is_empty()
+        || dialogue_runner.is_waiting_for_option_selection()
+    {
+        return;
+    }
+    *current_voice_line = CurrentVoiceLine::None;
+    dialogue_runner.continue_in_next_update();
+}
@@ -0,0 +1,163 @@
+//! Yarn commands that let dialogue affect the game world. They are registered on the [`DialogueRunner`] in [`add_yarn_commands`].
+//!
+//! - `<<follow>>` and `<<stay>>` make the character we are talking to follow the player or stay where it is.
+//! - `<<give_item key>>` puts an item into the player's inventory.
+//! - `<<play_sound "audio/sound_effects/throw.ogg">>` plays a sound and waits until it is finished.
+//! - `<<set_light lamp false>>` switches all lamps with the given `targetname` off or on, along with their groups.
+//!   Other lights with that `targetname` are hidden or shown.
+//! - `<<fire_target door>>` fires all entities with the given `targetname`.
+//!
+//! `<<wait 1.5>>` is built into Yarn Spinner.
+//!
+//! A command that takes time returns an `Arc<AtomicBool>`. The dialogue does not continue until it is set to `true`.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use bevy::prelude::*;
+use bevy_landmass::prelude::AgentTarget3d;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::prelude::*;
+
+use crate::{
+    PostPhysicsAppSystems,
+    audio::sound_effect,
+    gameplay::{
+        npc::ai::{Agent, WantsToFollowPlayer},
+        player::inventory::Inventory,
+        targets::{FireTarget, TargetName},
+    },
+    props::lamp::Lamps,
+    screens::Screen,
+};
+
+use super::InConversation;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<CompletesYarnCommand>();
+    app.add_observer(complete_yarn_command_on_despawn);
+    app.add_systems(
+        Update,
+        despawn_sounds_that_failed_to_load
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::PlaySounds),
+    );
+}
+
+pub(crate) fn add_yarn_commands(dialogue_runner: &mut DialogueRunner, commands: &mut Commands) {
+    dialogue_runner
+        .commands_mut()
+        .add_command("follow", commands.register_system(follow))
+        .add_command("stay", commands.register_system(stay))
+        .add_command("give_item", commands.register_system(give_item))
+        .add_command("play_sound", commands.register_system(play_sound))
+        .add_command("set_light", commands.register_system(set_light))
+        .add_command("fire_target", commands.register_system(fire_target));
+}
+
+/// The blocking Yarn command that is waiting for this entity. It completes when the entity is despawned.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+struct CompletesYarnCommand(#[reflect(ignore)] Arc<AtomicBool>);
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn follow(_: In<()>, q_speaker: Query<&Agent, With<InConversation>>, mut commands: Commands) {
+    for agent in &q_speaker {
+        commands.entity(**agent).insert(WantsToFollowPlayer);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stay(
+    _: In<()>,
+    q_speaker: Query<&Agent, With<InConversation>>,
+    mut q_agent_target: Query<&mut AgentTarget3d>,
+    mut commands: Commands,
+) {
+    for agent in &q_speaker {
+        commands.entity(**agent).remove::<WantsToFollowPlayer>();
+        if let Ok(mut target) = q_agent_target.get_mut(**agent) {
+            *target = AgentTarget3d::None;
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn give_item(In(item): In<String>, mut inventory: Single<&mut Inventory>) {
+    inventory.add(item, 1);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn play_sound(
+    In(path): In<String>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) -> Arc<AtomicBool> {
+    let finished = Arc::new(AtomicBool::new(false));
+    commands.spawn((
+        Name::new("Dialogue Sound"),
+        sound_effect(asset_server.load(path)),
+        CompletesYarnCommand(finished.clone()),
+        StateScoped(Screen::Gameplay),
+    ));
+    finished
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn set_light(
+    In((targetname, on)): In<(String, bool)>,
+    q_target: Query<(Entity, &TargetName)>,
+    q_children: Query<&Children>,
+    mut lamps: Lamps,
+    mut q_light: Query<&mut Visibility, Or<(With<PointLight>, With<SpotLight>)>>,
+) {
+    let visibility = if on {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for (entity, _) in q_target.iter().filter(|(_, name)| name.is(&targetname)) {
+        if lamps.contains(entity) {
+            lamps.set_lamp(entity, on);
+            continue;
+        }
+        // Lights that are not part of a lamp have no state to switch, so we just hide them.
+        let mut lights = q_light
+            .iter_many_mut(std::iter::once(entity).chain(q_children.iter_descendants(entity)));
+        while let Some(mut light_visibility) = lights.fetch_next() {
+            *light_visibility = visibility;
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn fire_target(In(targetname): In<String>, mut commands: Commands) {
+    commands.trigger(FireTarget(targetname));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn complete_yarn_command_on_despawn(
+    trigger: Trigger<OnRemove, CompletesYarnCommand>,
+    q_command: Query<&CompletesYarnCommand>,
+) {
+    let command = q_command.get(trigger.target()).unwrap();
+    command.0.store(true, Ordering::Relaxed);
+}
+
+/// A sound that failed to load never finishes playing, so we would wait for it forever.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn despawn_sounds_that_failed_to_load(
+    q_sound: Query<(Entity, &AudioPlayer), With<CompletesYarnCommand>>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    for (entity, player) in &q_sound {
+        if asset_server.load_state(&player.0).is_failed() {
+            warn!("Failed to load dialogue sound, continuing without it.");
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Yarn functions that let dialogue query the game state. They are registered on the [`DialogueRunner`] in [`add_yarn_functions`].
+//!
+//! - `has_item("key")` returns whether the player carries the item.
+//! - `visited_room("cellar")` returns whether the player has entered the `trigger_room` with that name.
+//! - `distance_to_player("follower")` returns the distance in meters between the player and the entity with that `targetname`.
+//!
+//! Yarn functions are plain Rust closures without access to the ECS, so they read from a [`YarnGameState`] snapshot
+//! that is updated every frame before the dialogue runner runs.
+
+use std::sync::{Arc, RwLock};
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_yarnspinner::prelude::*;
+
+use crate::{
+    gameplay::{
+        player::{Player, inventory::Inventory},
+        targets::TargetName,
+    },
+    props::brush_entity::room::VisitedRooms,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<YarnGameState>();
+    app.add_systems(
+        Update,
+        update_yarn_game_state
+            .before(YarnSpinnerSystemSet)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+pub(crate) fn add_yarn_functions(dialogue_runner: &mut DialogueRunner, game_state: &YarnGameState) {
+    let state = game_state.0.clone();
+    dialogue_runner
+        .library_mut()
+        .add_function("has_item", move |item: &str| {
+            state.read().unwrap().items.contains(item)
+        });
+    let state = game_state.0.clone();
+    dialogue_runner
+        .library_mut()
+        .add_function("visited_room", move |room: &str| {
+            state.read().unwrap().visited_rooms.contains(room)
+        });
+    let state = game_state.0.clone();
+    dialogue_runner
+        .library_mut()
+        .add_function("distance_to_player", move |targetname: &str| {
+            let distance = state.read().unwrap().distances.get(targetname).copied();
+            distance.unwrap_or_else(|| {
+                warn!("distance_to_player: No entity has the targetname \"{targetname}\".");
+                f32::INFINITY
+            })
+        });
+}
+
+/// The parts of the game state that Yarn functions can query.
+#[derive(Resource, Debug, Default, Clone)]
+pub(crate) struct YarnGameState(Arc<RwLock<YarnGameStateSnapshot>>);
+
+#[derive(Debug, Default)]
+struct YarnGameStateSnapshot {
+    items: HashSet<String>,
+    visited_rooms: HashSet<String>,
+    /// The distance to the player for each `targetname`. If multiple entities share a name, the closest one counts.
+    distances: HashMap<String, f32>,
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_yarn_game_state(
+    game_state: Res<YarnGameState>,
+    player: Single<(&GlobalTransform, &Inventory), With<Player>>,
+    visited_rooms: Res<VisitedRooms>,
+    q_target: Query<(&TargetName, &GlobalTransform)>,
+) {
+    let (player_transform, inventory) = player.into_inner();
+    let mut state = game_state.0.write().unwrap();
+
+    state.items.clear();
+    state
+        .items
+        .extend(inventory.iter().map(|(item, _)| item.to_string()));
+
+    if visited_rooms.is_changed() {
+        state.visited_rooms.clone_from(&visited_rooms.0);
+    }
+
+    state.distances.clear();
+    for (name, transform) in &q_target {
+        if name.targetname.is_empty() {
+            continue;
+        }
+        let distance = transform
+            .translation()
+            .distance(player_transform.translation());
+        state
+            .distances
+            .entry(name.targetname.clone())
+            .and_modify(|closest| *closest = closest.min(distance))
+            .or_insert(distance);
+    }
+}
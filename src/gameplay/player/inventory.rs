@@ -0,0 +1,187 @@
+//! The items the player carries. Items are identified by a string ID, so that levels and dialogue can refer to them by name.
+//! Using an [`Item`] in the world puts it into the [`Inventory`]. Dropping it from the inventory menu puts it back into the world.
+//! The inventory is part of the save game, including the items the player dropped.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use serde::{Deserialize, Serialize};
+
+use super::{Player, camera::PlayerCamera};
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::interaction::{Interacted, InteractionAction},
+    props::item::{DroppedItem, Item, LevelItem},
+    save::SaveGame,
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Inventory>();
+    app.add_observer(pick_up_item);
+    app.add_observer(drop_item);
+    app.add_systems(
+        Update,
+        save_inventory
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(Component, Debug, Clone, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct Inventory {
+    /// The items the player carries by their ID. Sorted so that the inventory menu has a stable order.
+    items: BTreeMap<String, InventoryItem>,
+}
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct InventoryItem {
+    pub(crate) name: String,
+    /// The model to use when the item is dropped. Items given by dialogue have none and cannot be dropped.
+    pub(crate) model: Option<String>,
+    pub(crate) count: u32,
+}
+
+impl Inventory {
+    /// Add an item that only has an ID, e.g. one given by dialogue.
+    pub(crate) fn add(&mut self, item: impl Into<String>, count: u32) {
+        let item = item.into();
+        self.add_named(&item, &item, count);
+    }
+
+    /// Add an item that has no model, e.g. loot from a container, under the given name.
+    pub(crate) fn add_named(&mut self, item: &str, name: &str, count: u32) {
+        if count == 0 {
+            return;
+        }
+        self.items
+            .entry(item.to_string())
+            .or_insert_with(|| InventoryItem {
+                name: name.to_string(),
+                model: None,
+                count: 0,
+            })
+            .count += count;
+    }
+
+    /// Add an item from the world.
+    pub(crate) fn add_item(&mut self, item: &Item) {
+        let entry = self
+            .items
+            .entry(item.item.clone())
+            .or_insert_with(|| InventoryItem {
+                name: item.name.clone(),
+                model: None,
+                count: 0,
+            });
+        if !item.model.is_empty() {
+            entry.model = Some(item.model.clone());
+        }
+        entry.count += 1;
+    }
+
+    /// Take one of the given item out of the inventory.
+    pub(crate) fn remove(&mut self, item: &str) {
+        let Some(entry) = self.items.get_mut(item) else {
+            return;
+        };
+        entry.count -= 1;
+        if entry.count == 0 {
+            self.items.remove(item);
+        }
+    }
+
+    pub(crate) fn get(&self, item: &str) -> Option<&InventoryItem> {
+        self.items.get(item)
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &InventoryItem)> {
+        self.items.iter().map(|(id, item)| (id.as_str(), item))
+    }
+}
+
+/// Trigger this to drop one of the given item in front of the player.
+#[derive(Event, Debug, Clone)]
+pub(crate) struct DropItem(pub(crate) String);
+
+/// How far in front of the camera dropped items appear.
+const DROP_DISTANCE: f32 = 1.0;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn pick_up_item(
+    trigger: Trigger<Interacted>,
+    q_item: Query<(&Item, Option<&LevelItem>)>,
+    mut inventory: Single<&mut Inventory, With<Player>>,
+    mut save_game: ResMut<SaveGame>,
+    mut commands: Commands,
+) {
+    if trigger.event().0 != InteractionAction::PickUp {
+        return;
+    }
+    let Ok((item, level_item)) = q_item.get(trigger.target()) else {
+        return;
+    };
+    inventory.add_item(item);
+    if let Some(level_item) = level_item {
+        save_game.collected_items.insert(level_item.0.clone());
+    }
+    commands.entity(trigger.target()).despawn();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn drop_item(
+    trigger: Trigger<DropItem>,
+    mut inventory: Single<&mut Inventory, With<Player>>,
+    camera: Single<&GlobalTransform, With<PlayerCamera>>,
+    mut commands: Commands,
+) {
+    let id = &trigger.event().0;
+    let Some(InventoryItem {
+        name,
+        model: Some(model),
+        ..
+    }) = inventory.get(id).cloned()
+    else {
+        return;
+    };
+    inventory.remove(id);
+    let camera_transform = camera.compute_transform();
+    commands.spawn((
+        Name::new(format!("Dropped {name}")),
+        Transform::from_translation(
+            camera_transform.translation + camera_transform.forward() * DROP_DISTANCE,
+        ),
+        // Keeps `setup_item` from treating this as an item placed in the level.
+        DroppedItem,
+        Item {
+            item: id.clone(),
+            name,
+            model,
+        },
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+/// Dropped items are not part of the level, so loading a save would lose them.
+/// Instead, the save game keeps them in the inventory until they are picked up again.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn save_inventory(
+    inventory: Single<Ref<Inventory>, With<Player>>,
+    q_dropped: Query<&Item, With<DroppedItem>>,
+    q_newly_dropped: Query<(), Added<DroppedItem>>,
+    mut removed_dropped: RemovedComponents<DroppedItem>,
+    mut save_game: ResMut<SaveGame>,
+) {
+    let dropped_changed = !q_newly_dropped.is_empty() || removed_dropped.read().count() > 0;
+    if !inventory.is_changed() && !dropped_changed {
+        return;
+    }
+    let mut saved_inventory = Inventory::clone(&inventory);
+    for item in &q_dropped {
+        saved_inventory.add_item(item);
+    }
+    save_game.inventory = saved_inventory;
+}
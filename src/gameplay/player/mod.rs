@@ -16,13 +16,14 @@ use bevy_trenchbroom::prelude::*;
 use default_input::DefaultInputContext;
 use navmesh_position::LastValidPlayerNavmeshPosition;
 
-use crate::third_party::avian3d::CollisionLayer;
+use crate::{gameplay::seating::Sitter, save::SaveGame, third_party::avian3d::CollisionLayer};
 
 mod animation;
 pub(crate) mod assets;
 pub(crate) mod camera;
 pub(crate) mod default_input;
 pub(crate) mod dialogue;
+pub(crate) mod inventory;
 pub(crate) mod movement;
 pub(crate) mod movement_sound;
 pub(crate) mod navmesh_position;
@@ -36,6 +37,7 @@ pub(super) fn plugin(app: &mut App) {
         camera::plugin,
         default_input::plugin,
         dialogue::plugin,
+        inventory::plugin,
         movement::plugin,
         movement_sound::plugin,
         pickup::plugin,
@@ -76,6 +78,7 @@ fn setup_player(
     trigger: Trigger<OnAdd, Player>,
     mut commands: Commands,
     archipelago: Single<Entity, With<Archipelago3d>>,
+    save_game: Res<SaveGame>,
 ) {
     commands
         .entity(trigger.target())
@@ -101,6 +104,9 @@ fn setup_player(
             ColliderDensity(100.0),
             CollisionLayers::new(CollisionLayer::Character, LayerMask::ALL),
             TnuaAnimatingState::<PlayerAnimationState>::default(),
+            save_game.inventory.clone(),
+            // The camera follows the player's origin, so putting it right on the seat lowers the camera.
+            Sitter { hip_height: 0.0 },
             children![(
                 Name::new("Player Landmass Character"),
                 Transform::from_xyz(0.0, -PLAYER_FLOAT_HEIGHT, 0.0),
@@ -6,7 +6,7 @@ use bevy_enhanced_input::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_tnua::prelude::*;
 
-use crate::fixed_update_inspection::did_fixed_update_happen;
+use crate::{fixed_update_inspection::did_fixed_update_happen, gameplay::seating::SittingOn};
 
 use super::default_input::{Jump, Move};
 
@@ -82,8 +82,14 @@ fn apply_movement(
 }
 
 #[cfg_attr(feature = "hot_patch", hot)]
-fn jump(trigger: Trigger<Fired<Jump>>, mut controllers: Query<&mut TnuaController>) {
-    let mut controller = controllers.get_mut(trigger.target()).unwrap();
+fn jump(
+    trigger: Trigger<Fired<Jump>>,
+    mut controllers: Query<&mut TnuaController, Without<SittingOn>>,
+) {
+    // Jumping while sitting makes the player stand up instead, see `gameplay::seating`.
+    let Ok(mut controller) = controllers.get_mut(trigger.target()) else {
+        return;
+    };
     controller.action(TnuaBuiltinJump {
         // The height is the only mandatory field of the jump button.
         height: 1.5,
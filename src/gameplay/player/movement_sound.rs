@@ -1,12 +1,17 @@
 use std::time::Duration;
 
 use avian3d::prelude::LinearVelocity;
-use bevy::prelude::*;
+use bevy::{audio::Volume, prelude::*};
 #[cfg(feature = "hot_patch")]
 use bevy_simple_subsecond_system::hot;
-use bevy_tnua::{builtins::TnuaBuiltinJumpState, prelude::*};
+use bevy_tnua::{TnuaProximitySensor, builtins::TnuaBuiltinJumpState, prelude::*};
 
-use crate::{PostPhysicsAppSystems, audio::sound_effect, screens::Screen};
+use crate::{
+    PostPhysicsAppSystems,
+    audio::{SoundEffect, sound_effect},
+    props::physics_material::{FootstepSurface, GroundMaterial, PhysicsMaterial},
+    screens::Screen,
+};
 
 use super::{Player, assets::PlayerAssets};
 
@@ -55,10 +60,50 @@ fn play_jump_grunt(
     }
 }
 
+/// What the player is standing on.
+fn ground_surface(
+    sensor: &TnuaProximitySensor,
+    transform: &GlobalTransform,
+    ground_material: &mut GroundMaterial,
+) -> FootstepSurface {
+    sensor
+        .output
+        .as_ref()
+        .and_then(|output| ground_material.get(output.entity, transform.translation()))
+        .map(PhysicsMaterial::footstep_surface)
+        .unwrap_or_default()
+}
+
+/// We only have recordings of steps on rock, so other surfaces are approximated by changing their pitch and volume.
+fn footstep_sound(handle: Handle<AudioSource>, surface: FootstepSurface) -> impl Bundle {
+    let (speed, volume) = match surface {
+        FootstepSurface::Stone => (1.0, 1.0),
+        FootstepSurface::Wood => (1.15, 0.9),
+        FootstepSurface::Metal => (1.3, 1.1),
+        FootstepSurface::Soft => (0.8, 0.5),
+    };
+    (
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN
+            .with_speed(speed)
+            .with_volume(Volume::Linear(volume)),
+        SoundEffect,
+    )
+}
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn play_step_sound(
     mut commands: Commands,
-    player: Single<(&TnuaController, &LinearVelocity), With<Player>>,
+    player: Single<
+        (
+            &TnuaController,
+            &TnuaProximitySensor,
+            &GlobalTransform,
+            &LinearVelocity,
+        ),
+        With<Player>,
+    >,
+    mut ground_material: GroundMaterial,
     mut player_assets: ResMut<PlayerAssets>,
     time: Res<Time>,
     mut timer: Local<Option<Timer>>,
@@ -70,7 +115,7 @@ fn play_step_sound(
         return;
     }
 
-    let (controller, linear_velocity) = player.into_inner();
+    let (controller, sensor, transform, linear_velocity) = player.into_inner();
     if controller.is_airborne().unwrap_or(true) {
         return;
     }
@@ -79,17 +124,20 @@ fn play_step_sound(
     }
     let rng = &mut rand::thread_rng();
     let sound = player_assets.steps.pick(rng).clone();
-    commands.spawn(sound_effect(sound));
+    let surface = ground_surface(sensor, transform, &mut ground_material);
+    commands.spawn(footstep_sound(sound, surface));
 }
 
 #[cfg_attr(feature = "hot_patch", hot)]
 fn play_land_sound(
     mut commands: Commands,
-    player: Single<&TnuaController, With<Player>>,
+    player: Single<(&TnuaController, &TnuaProximitySensor, &GlobalTransform), With<Player>>,
+    mut ground_material: GroundMaterial,
     mut player_assets: ResMut<PlayerAssets>,
     mut was_airborne: Local<bool>,
 ) {
-    let is_airborne = player.is_airborne().unwrap_or(true);
+    let (controller, sensor, transform) = player.into_inner();
+    let is_airborne = controller.is_airborne().unwrap_or(true);
     if is_airborne {
         *was_airborne = true;
         return;
@@ -101,5 +149,6 @@ fn play_land_sound(
 
     let rng = &mut rand::thread_rng();
     let sound = player_assets.land_sounds.pick(rng).clone();
-    commands.spawn(sound_effect(sound));
+    let surface = ground_surface(sensor, transform, &mut ground_material);
+    commands.spawn(footstep_sound(sound, surface));
 }
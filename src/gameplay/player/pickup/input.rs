@@ -1,4 +1,4 @@
-//! Forward the player's input to the pickup plugin.
+//! Forward the player's input to the pickup plugin. Throwing is handled in [`super::throw`].
 
 use bevy::prelude::*;
 #[cfg(feature = "hot_patch")]
@@ -11,7 +11,6 @@ use crate::gameplay::player::default_input::{DropProp, PickupProp};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(pull_prop);
-    app.add_observer(throw_prop);
     app.add_observer(drop_prop);
 }
 
@@ -27,18 +26,6 @@ fn pull_prop(
     });
 }
 
-#[cfg_attr(feature = "hot_patch", hot)]
-fn throw_prop(
-    _trigger: Trigger<Started<PickupProp>>,
-    actor: Single<Entity, With<AvianPickupActor>>,
-    mut avian_pickup_input_writer: EventWriter<AvianPickupInput>,
-) {
-    avian_pickup_input_writer.write(AvianPickupInput {
-        action: AvianPickupAction::Throw,
-        actor: *actor,
-    });
-}
-
 #[cfg_attr(feature = "hot_patch", hot)]
 fn drop_prop(
     _trigger: Trigger<Started<DropProp>>,
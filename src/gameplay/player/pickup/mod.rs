@@ -5,11 +5,20 @@ use bevy::prelude::*;
 
 mod collision;
 mod input;
+pub(crate) mod rotation;
 mod sound;
+pub(crate) mod throw;
 mod ui;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins((collision::plugin, input::plugin, sound::plugin, ui::plugin));
+    app.add_plugins((
+        collision::plugin,
+        input::plugin,
+        rotation::plugin,
+        sound::plugin,
+        throw::plugin,
+        ui::plugin,
+    ));
 }
 
 pub(crate) fn is_holding_prop(q_prop: Query<&HeldProp>) -> bool {
@@ -0,0 +1,202 @@
+//! Placing held props precisely. While [`RotateHeldProp`] is held, looking around rotates the held prop instead of the camera.
+//! Holding [`SnapHeldPropRotation`] as well snaps the rotation to steps of 15°. [`AdjustHoldDistance`] moves the prop closer or farther away.
+//!
+//! `avian_pickup` holds props at their [`PreferredPickupRotation`] relative to the camera, so that is what we change.
+//! A prop's own preferred rotation is where rotating starts, and it is restored once the prop is let go.
+
+use std::f32::consts::TAU;
+
+use avian_pickup::{
+    prelude::*,
+    prop::{HeldProp, PreferredPickupRotation},
+};
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::gameplay::player::default_input::{
+    AdjustHoldDistance, Rotate, RotateHeldProp, SnapHeldPropRotation,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<HeldPropControl>();
+    app.register_type::<HeldPropRotation>();
+    app.add_observer(init_held_prop_control);
+    app.add_observer(start_rotating);
+    app.add_observer(stop_rotating);
+    app.add_observer(start_snapping);
+    app.add_observer(stop_snapping);
+    app.add_observer(rotate_held_prop);
+    app.add_observer(adjust_hold_distance);
+    app.add_observer(reset_after_letting_go);
+}
+
+/// How much faster a prop rotates than the camera for the same input.
+const ROTATION_SENSITIVITY: f32 = 2.0;
+/// 15°
+const SNAP_ANGLE: f32 = TAU / 24.0;
+/// How much one step of the scroll wheel moves the held prop, in meters.
+const HOLD_DISTANCE_STEP: f32 = 0.1;
+const MIN_HOLD_DISTANCE: f32 = 0.8;
+/// Must stay below the actor's `distance_to_allow_holding`, otherwise the prop is dropped.
+const MAX_HOLD_DISTANCE: f32 = 1.8;
+
+/// Which modifiers for holding props the player is pressing. Lives on the [`AvianPickupActor`].
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct HeldPropControl {
+    pub(crate) rotating: bool,
+    snapping: bool,
+}
+
+/// How the player rotated the held prop so far.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+struct HeldPropRotation {
+    /// The rotation relative to the camera when the player started rotating.
+    base: Quat,
+    yaw: f32,
+    pitch: f32,
+    /// The prop's own [`PreferredPickupRotation`], if it had one.
+    original: Option<Quat>,
+}
+
+impl HeldPropRotation {
+    fn rotation(&self, snapping: bool) -> Quat {
+        let snap = |angle: f32| {
+            if snapping {
+                (angle / SNAP_ANGLE).round() * SNAP_ANGLE
+            } else {
+                angle
+            }
+        };
+        Quat::from_rotation_y(snap(self.yaw)) * Quat::from_rotation_x(snap(self.pitch)) * self.base
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn init_held_prop_control(trigger: Trigger<OnAdd, AvianPickupActor>, mut commands: Commands) {
+    commands
+        .entity(trigger.target())
+        .insert(HeldPropControl::default());
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn start_rotating(
+    _trigger: Trigger<Started<RotateHeldProp>>,
+    mut control: Single<&mut HeldPropControl>,
+) {
+    control.rotating = true;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stop_rotating(
+    _trigger: Trigger<Completed<RotateHeldProp>>,
+    mut control: Single<&mut HeldPropControl>,
+) {
+    control.rotating = false;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn start_snapping(
+    _trigger: Trigger<Started<SnapHeldPropRotation>>,
+    mut control: Single<&mut HeldPropControl>,
+) {
+    control.snapping = true;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stop_snapping(
+    _trigger: Trigger<Completed<SnapHeldPropRotation>>,
+    mut control: Single<&mut HeldPropControl>,
+) {
+    control.snapping = false;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn rotate_held_prop(
+    trigger: Trigger<Fired<Rotate>>,
+    actor: Single<(&GlobalTransform, &HeldPropControl), With<AvianPickupActor>>,
+    mut q_prop: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            Option<&PreferredPickupRotation>,
+            Option<&mut HeldPropRotation>,
+        ),
+        With<HeldProp>,
+    >,
+    mut commands: Commands,
+) {
+    let (actor_transform, control) = actor.into_inner();
+    if !control.rotating {
+        return;
+    }
+    let Ok((prop, prop_transform, preferred_rotation, rotation)) = q_prop.single_mut() else {
+        return;
+    };
+    let delta = trigger.value * ROTATION_SENSITIVITY;
+    let rotation = match rotation {
+        Some(mut rotation) => {
+            rotation.yaw += delta.x;
+            rotation.pitch += delta.y;
+            rotation.rotation(control.snapping)
+        }
+        None => {
+            let original = preferred_rotation.map(|rotation| rotation.0);
+            let base = original.unwrap_or_else(|| {
+                actor_transform.rotation().inverse() * prop_transform.rotation()
+            });
+            let rotation = HeldPropRotation {
+                base,
+                yaw: delta.x,
+                pitch: delta.y,
+                original,
+            };
+            let quat = rotation.rotation(control.snapping);
+            commands.entity(prop).insert(rotation);
+            quat
+        }
+    };
+    commands
+        .entity(prop)
+        .insert(PreferredPickupRotation(rotation));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn adjust_hold_distance(
+    trigger: Trigger<Fired<AdjustHoldDistance>>,
+    mut actor: Single<&mut AvianPickupActor>,
+    q_held_prop: Query<(), With<HeldProp>>,
+) {
+    if q_held_prop.is_empty() {
+        return;
+    }
+    let distance = actor.hold.preferred_distance + trigger.value * HOLD_DISTANCE_STEP;
+    actor.hold.preferred_distance = distance.clamp(MIN_HOLD_DISTANCE, MAX_HOLD_DISTANCE);
+}
+
+/// The next prop should start out the way it prefers to be held.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn reset_after_letting_go(
+    trigger: Trigger<OnRemove, HeldProp>,
+    q_rotation: Query<&HeldPropRotation>,
+    // The actor is already gone when we quit to the title screen.
+    actor: Option<Single<&mut AvianPickupActor>>,
+    mut commands: Commands,
+) {
+    let prop = trigger.target();
+    if let Ok(rotation) = q_rotation.get(prop) {
+        // The prop may be getting despawned.
+        let mut prop_commands = commands.entity(prop);
+        prop_commands.try_remove::<HeldPropRotation>();
+        match rotation.original {
+            Some(original) => prop_commands.try_insert(PreferredPickupRotation(original)),
+            None => prop_commands.try_remove::<PreferredPickupRotation>(),
+        };
+    }
+    if let Some(mut actor) = actor {
+        actor.hold.preferred_distance = AvianPickupActorHoldConfig::default().preferred_distance;
+    }
+}
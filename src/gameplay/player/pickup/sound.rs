@@ -1,6 +1,5 @@
 //! Player pickup sound effects.
 
-use avian_pickup::output::PropThrown;
 use bevy::{audio::Volume, prelude::*};
 #[cfg(feature = "hot_patch")]
 use bevy_simple_subsecond_system::hot;
@@ -10,22 +9,32 @@ use crate::{
     screens::Screen,
 };
 
+use super::throw::PropThrownWithStrength;
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
         play_throw_sound
-            .run_if(in_state(Screen::Gameplay).and(on_event::<PropThrown>))
+            .run_if(in_state(Screen::Gameplay).and(on_event::<PropThrownWithStrength>))
             .in_set(PostPhysicsAppSystems::PlaySounds),
     );
 }
 
 #[cfg_attr(feature = "hot_patch", hot)]
-fn play_throw_sound(mut commands: Commands, player_assets: Res<PlayerAssets>) {
-    let sound = player_assets.throw_sound.clone();
+fn play_throw_sound(
+    mut commands: Commands,
+    player_assets: Res<PlayerAssets>,
+    mut thrown_reader: EventReader<PropThrownWithStrength>,
+) {
+    for thrown in thrown_reader.read() {
+        let sound = player_assets.throw_sound.clone();
+        // Stronger throws are louder.
+        let volume = Volume::Linear(3.0 * thrown.strength);
 
-    commands.spawn((
-        AudioPlayer(sound),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(3.0)),
-        SoundEffect,
-    ));
+        commands.spawn((
+            AudioPlayer(sound),
+            PlaybackSettings::DESPAWN.with_volume(volume),
+            SoundEffect,
+        ));
+    }
 }
@@ -0,0 +1,141 @@
+//! Charged throws. Holding [`PickupProp`] while holding a prop charges a throw, releasing it throws the prop.
+//! The longer the charge, the stronger the throw. Heavy props fly slower than light ones.
+//!
+//! We let `avian_pickup` drop the prop and apply the throw ourselves, since its own throw has a fixed strength.
+
+use avian_pickup::{prelude::*, prop::HeldProp};
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{PostPhysicsAppSystems, gameplay::player::default_input::PickupProp, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ThrowCharge>();
+    app.add_event::<PropThrownWithStrength>();
+    app.add_observer(start_charging_throw);
+    app.add_observer(release_throw);
+    app.add_observer(apply_throw);
+    app.add_systems(
+        Update,
+        charge_throw
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+/// How long it takes to charge a throw fully, in seconds.
+const MAX_CHARGE_TIME: f32 = 1.0;
+/// How strong a throw is when the button is only tapped.
+const MIN_THROW_STRENGTH: f32 = 0.2;
+/// The impulse of a fully charged throw, in N·s.
+const MAX_THROW_IMPULSE: f32 = 120.0;
+/// Light props would fly off way too fast otherwise.
+const MAX_THROW_SPEED: f32 = 15.0;
+
+/// A throw being charged. Lives on the [`AvianPickupActor`].
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct ThrowCharge {
+    seconds: f32,
+}
+
+impl ThrowCharge {
+    /// How strong the throw would be if released now, between [`MIN_THROW_STRENGTH`] and 1.
+    pub(crate) fn strength(&self) -> f32 {
+        let charge = (self.seconds / MAX_CHARGE_TIME).min(1.0);
+        MIN_THROW_STRENGTH.lerp(1.0, charge)
+    }
+}
+
+/// A throw that is waiting for `avian_pickup` to let go of the prop.
+#[derive(Component, Debug)]
+struct PendingThrow {
+    strength: f32,
+}
+
+/// Sent when the player threw a prop, with the strength of the throw between 0 and 1.
+#[derive(Event, Debug, Clone, Copy)]
+pub(super) struct PropThrownWithStrength {
+    pub(super) strength: f32,
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn start_charging_throw(
+    _trigger: Trigger<Started<PickupProp>>,
+    actor: Single<Entity, With<AvianPickupActor>>,
+    q_held_prop: Query<(), With<HeldProp>>,
+    mut commands: Commands,
+) {
+    if !q_held_prop.is_empty() {
+        commands.entity(*actor).insert(ThrowCharge::default());
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn charge_throw(time: Res<Time>, mut charge: Query<&mut ThrowCharge>) {
+    for mut charge in &mut charge {
+        charge.seconds += time.delta_secs();
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn release_throw(
+    _trigger: Trigger<Completed<PickupProp>>,
+    actor: Single<(Entity, Option<&ThrowCharge>), With<AvianPickupActor>>,
+    mut avian_pickup_input_writer: EventWriter<AvianPickupInput>,
+    mut commands: Commands,
+) {
+    let (actor, charge) = actor.into_inner();
+    let Some(charge) = charge else {
+        return;
+    };
+    commands
+        .entity(actor)
+        .remove::<ThrowCharge>()
+        .insert(PendingThrow {
+            strength: charge.strength(),
+        });
+    avian_pickup_input_writer.write(AvianPickupInput {
+        action: AvianPickupAction::Drop,
+        actor,
+    });
+}
+
+/// Dropping the prop in any other way cancels the charge.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn apply_throw(
+    trigger: Trigger<OnRemove, HeldProp>,
+    // The actor is already gone when we quit to the title screen.
+    actor: Option<
+        Single<(Entity, &GlobalTransform, Option<&PendingThrow>), With<AvianPickupActor>>,
+    >,
+    q_mass: Query<&ComputedMass>,
+    mut thrown_writer: EventWriter<PropThrownWithStrength>,
+    mut commands: Commands,
+) {
+    let Some((actor, transform, pending_throw)) = actor.map(|actor| actor.into_inner()) else {
+        return;
+    };
+    commands
+        .entity(actor)
+        .remove::<(ThrowCharge, PendingThrow)>();
+    let Some(pending_throw) = pending_throw else {
+        return;
+    };
+    let prop = trigger.target();
+    let Ok(mass) = q_mass.get(prop) else {
+        return;
+    };
+    let mass = mass.value().max(f32::EPSILON);
+    let speed = (MAX_THROW_IMPULSE * pending_throw.strength / mass).min(MAX_THROW_SPEED);
+    // The prop may be getting despawned.
+    commands
+        .entity(prop)
+        .try_insert(ExternalImpulse::new(transform.forward() * speed * mass));
+    thrown_writer.write(PropThrownWithStrength {
+        strength: pending_throw.strength,
+    });
+}
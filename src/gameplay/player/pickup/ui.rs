@@ -1,11 +1,12 @@
 //! Player pickup UI interactions.
 //! In particular, change the crosshair when the player is looking at a prop and hide it when the player is holding a prop.
+//! While a throw is charged, a bar in place of the crosshair shows its strength.
 
 use std::any::Any as _;
 
 use avian_pickup::{actor::AvianPickupActor, prop::HeldProp};
 use avian3d::prelude::*;
-use bevy::prelude::*;
+use bevy::{prelude::*, ui::Val::*};
 #[cfg(feature = "hot_patch")]
 use bevy_simple_subsecond_system::hot;
 
@@ -13,9 +14,12 @@ use crate::{
     PostPhysicsAppSystems,
     gameplay::{crosshair::CrosshairState, player::camera::PlayerCamera},
     screens::Screen,
+    theme::palette::{BUTTON_BACKGROUND, PANEL_BACKGROUND},
     third_party::avian3d::CollisionLayer,
 };
 
+use super::throw::ThrowCharge;
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
@@ -23,6 +27,13 @@ pub(super) fn plugin(app: &mut App) {
             .run_if(in_state(Screen::Gameplay))
             .in_set(PostPhysicsAppSystems::ChangeUi),
     );
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_throw_charge_indicator);
+    app.add_systems(
+        Update,
+        update_throw_charge_indicator
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
     app.add_observer(hide_crosshair_when_picking_up);
     app.add_observer(show_crosshair_when_not_picking_up);
 }
@@ -69,3 +80,63 @@ fn show_crosshair_when_not_picking_up(
         .wants_invisible
         .remove(&hide_crosshair_when_picking_up.type_id());
 }
+
+#[derive(Component)]
+struct ThrowChargeIndicator;
+
+#[derive(Component)]
+struct ThrowChargeIndicatorFill;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_throw_charge_indicator(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Throw Charge Indicator"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Percent(100.0),
+            height: Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        ThrowChargeIndicator,
+        Visibility::Hidden,
+        StateScoped(Screen::Gameplay),
+        Pickable::IGNORE,
+        children![(
+            Name::new("Throw Charge Bar"),
+            Node {
+                width: Px(80.0),
+                height: Px(6.0),
+                ..default()
+            },
+            BackgroundColor(PANEL_BACKGROUND),
+            BorderRadius::all(Px(3.0)),
+            children![(
+                Name::new("Throw Charge Fill"),
+                Node {
+                    width: Percent(0.0),
+                    height: Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(BUTTON_BACKGROUND),
+                BorderRadius::all(Px(3.0)),
+                ThrowChargeIndicatorFill,
+            )],
+        )],
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_throw_charge_indicator(
+    charge: Option<Single<&ThrowCharge>>,
+    mut indicator: Single<&mut Visibility, With<ThrowChargeIndicator>>,
+    mut fill: Single<&mut Node, With<ThrowChargeIndicatorFill>>,
+) {
+    let Some(charge) = charge else {
+        indicator.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    indicator.set_if_neq(Visibility::Inherited);
+    fill.width = Percent(charge.strength() * 100.0);
+}
@@ -0,0 +1,204 @@
+//! Sitting on chairs. Anything with a [`Sitter`] can sit on a [`Seat`], as long as the seat is upright and free.
+//! Trigger [`SitDown`] or [`StandUp`] on the sitter to change its posture. The player sits by using a seat and stands up by jumping.
+//! While sitting, the sitter's physics are disabled and it is kept on the seat.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::{
+        hint::ShowHint,
+        interaction::{Interacted, InteractionAction},
+        player::{Player, default_input::Jump},
+    },
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Seat>();
+    app.register_type::<Sitter>();
+    app.register_type::<SittingOn>();
+    app.register_type::<SeatOccupant>();
+    app.add_observer(sit_down);
+    app.add_observer(stand_up);
+    app.add_observer(restore_physics_after_sitting);
+    app.add_observer(use_seat);
+    app.add_observer(stand_up_on_jump);
+    app.add_systems(
+        Update,
+        (stand_up_from_fallen_seats, keep_sitters_on_seats)
+            .chain()
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+/// Something that can be sat on.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Seat {
+    /// The height of the seat above the entity's origin.
+    pub(crate) height: f32,
+}
+
+/// Something that can sit on a [`Seat`].
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Sitter {
+    /// How far above the seat the sitter's origin is while sitting.
+    pub(crate) hip_height: f32,
+}
+
+#[derive(Component, Deref, Debug, Reflect)]
+#[reflect(Component)]
+#[relationship(relationship_target = SeatOccupant)]
+pub(crate) struct SittingOn(Entity);
+
+#[derive(Component, Deref, Debug, Reflect)]
+#[reflect(Component)]
+#[relationship_target(relationship = SittingOn)]
+pub(crate) struct SeatOccupant(Entity);
+
+/// Where the sitter was before sitting down, so that it can get back up there.
+#[derive(Component, Debug, Clone, Copy)]
+struct StandUpPosition(Vec3);
+
+/// Trigger this on a [`Sitter`] to make it sit on the given [`Seat`].
+#[derive(Event, Debug, Clone, Copy)]
+pub(crate) struct SitDown {
+    pub(crate) seat: Entity,
+}
+
+/// Trigger this on a [`Sitter`] to make it get up from its seat.
+#[derive(Event, Debug, Clone, Copy)]
+pub(crate) struct StandUp;
+
+/// The cosine of the largest tilt at which a seat can still be sat on, here 30 degrees.
+const MIN_UPRIGHTNESS: f32 = 0.866;
+
+/// Whether a seat stands upright enough to sit on.
+pub(crate) fn is_upright(transform: &GlobalTransform) -> bool {
+    transform.up().dot(Vec3::Y) > MIN_UPRIGHTNESS
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn sit_down(
+    trigger: Trigger<SitDown>,
+    q_sitter: Query<&Transform, (With<Sitter>, Without<SittingOn>)>,
+    q_seat: Query<(&GlobalTransform, Has<SeatOccupant>), With<Seat>>,
+    mut commands: Commands,
+) {
+    let sitter = trigger.target();
+    let seat = trigger.event().seat;
+    let Ok(transform) = q_sitter.get(sitter) else {
+        return;
+    };
+    let Ok((seat_transform, occupied)) = q_seat.get(seat) else {
+        return;
+    };
+    if occupied || !is_upright(seat_transform) {
+        return;
+    }
+    commands.entity(sitter).insert((
+        SittingOn(seat),
+        StandUpPosition(transform.translation),
+        RigidBodyDisabled,
+        ColliderDisabled,
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stand_up(trigger: Trigger<StandUp>, mut commands: Commands) {
+    commands.entity(trigger.target()).remove::<SittingOn>();
+}
+
+/// This also runs when the seat is despawned, which removes the [`SittingOn`] relationship.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn restore_physics_after_sitting(
+    trigger: Trigger<OnRemove, SittingOn>,
+    mut q_sitter: Query<(&mut Transform, &mut LinearVelocity, &StandUpPosition)>,
+    mut commands: Commands,
+) {
+    let sitter = trigger.target();
+    if let Ok((mut transform, mut velocity, stand_up_position)) = q_sitter.get_mut(sitter) {
+        transform.translation = stand_up_position.0;
+        velocity.0 = Vec3::ZERO;
+    }
+    // The sitter itself may be getting despawned.
+    commands
+        .entity(sitter)
+        .try_remove::<(StandUpPosition, RigidBodyDisabled, ColliderDisabled)>();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn use_seat(
+    trigger: Trigger<Interacted>,
+    player: Single<(Entity, Option<&SittingOn>), With<Player>>,
+    q_seat: Query<(&GlobalTransform, Has<SeatOccupant>), With<Seat>>,
+    mut commands: Commands,
+) {
+    if trigger.event().0 != InteractionAction::Sit {
+        return;
+    }
+    let seat = trigger.target();
+    let (player, sitting_on) = player.into_inner();
+    if sitting_on.is_some_and(|sitting_on| **sitting_on == seat) {
+        commands.trigger_targets(StandUp, player);
+        return;
+    }
+    let Ok((transform, occupied)) = q_seat.get(seat) else {
+        return;
+    };
+    if !is_upright(transform) {
+        commands.trigger(ShowHint("It has to be set upright first.".to_string()));
+    } else if occupied {
+        commands.trigger(ShowHint("Someone is already sitting there.".to_string()));
+    } else {
+        if sitting_on.is_some() {
+            commands.trigger_targets(StandUp, player);
+        }
+        commands.trigger_targets(SitDown { seat }, player);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stand_up_on_jump(
+    trigger: Trigger<Started<Jump>>,
+    q_sitting: Query<(), (With<Player>, With<SittingOn>)>,
+    mut commands: Commands,
+) {
+    if q_sitting.contains(trigger.target()) {
+        commands.trigger_targets(StandUp, trigger.target());
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn stand_up_from_fallen_seats(
+    q_seat: Query<(&GlobalTransform, &SeatOccupant)>,
+    mut commands: Commands,
+) {
+    for (transform, occupant) in &q_seat {
+        if !is_upright(transform) {
+            commands.trigger_targets(StandUp, **occupant);
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn keep_sitters_on_seats(
+    mut q_sitter: Query<(&mut Transform, &Sitter, &SittingOn)>,
+    q_seat: Query<(&GlobalTransform, &Seat), Without<Sitter>>,
+) {
+    for (mut transform, sitter, sitting_on) in &mut q_sitter {
+        let Ok((seat_transform, seat)) = q_seat.get(**sitting_on) else {
+            continue;
+        };
+        transform.translation = seat_transform.translation()
+            + seat_transform.up() * seat.height
+            + Vec3::Y * sitter.hip_height;
+    }
+}
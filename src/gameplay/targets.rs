@@ -0,0 +1,53 @@
+//! Quake-style targets. Any entity can be given a `targetname` in TrenchBroom.
+//! Firing that name, e.g. from a trigger or from dialogue, sends an [`Activate`] event to every entity carrying it.
+//! Entities react to being fired by observing [`Activate`].
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<TargetName>();
+    app.add_observer(fire_target);
+}
+
+#[derive(BaseClass, Component, Debug, Clone, Default, Reflect, Eq, PartialEq)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct TargetName {
+    /// The name other entities and dialogue use to fire this entity.
+    pub(crate) targetname: String,
+}
+
+impl TargetName {
+    pub(crate) fn is(&self, targetname: &str) -> bool {
+        !self.targetname.is_empty() && self.targetname == targetname
+    }
+}
+
+/// Trigger this to send [`Activate`] to all entities with the given [`TargetName`].
+#[derive(Event, Debug, Clone)]
+pub(crate) struct FireTarget(pub(crate) String);
+
+/// Sent to an entity when its [`TargetName`] is fired.
+#[derive(Event, Debug, Clone, Copy)]
+pub(crate) struct Activate;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn fire_target(
+    trigger: Trigger<FireTarget>,
+    q_target: Query<(Entity, &TargetName)>,
+    mut commands: Commands,
+) {
+    let targetname = &trigger.event().0;
+    let targets = q_target
+        .iter()
+        .filter(|(_, name)| name.is(targetname))
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    if targets.is_empty() {
+        warn!("Fired target \"{targetname}\", but no entity has that targetname.");
+        return;
+    }
+    commands.trigger_targets(Activate, targets);
+}
@@ -11,6 +11,7 @@ mod gameplay;
 mod hdr;
 mod menus;
 mod props;
+mod save;
 mod screens;
 mod shader_compilation;
 mod theme;
@@ -104,6 +105,7 @@ fn main() -> AppExit {
         screens::plugin,
         menus::plugin,
         props::plugin,
+        save::plugin,
         theme::plugin,
         ui_camera::plugin,
         hdr::plugin,
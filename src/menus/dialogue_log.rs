@@ -0,0 +1,95 @@
+//! A scrollable log of everything that was said in dialogue. It can be opened from the pause menu and while talking to someone.
+
+use bevy::{
+    ecs::spawn::SpawnIter, input::common_conditions::input_just_pressed, prelude::*, ui::Val::*,
+};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    Pause,
+    gameplay::player::dialogue::history::{DialogueHistory, DialogueHistoryEntry},
+    menus::{Menu, go_back, go_back_on_click},
+    theme::{
+        palette::{BODY_TEXT, DISABLED_TEXT, LABEL_TEXT, SCREEN_BACKGROUND},
+        prelude::*,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::DialogueLog), spawn_dialogue_log_menu);
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::DialogueLog).and(input_just_pressed(KeyCode::Escape))),
+    );
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_dialogue_log_menu(
+    mut commands: Commands,
+    history: Res<DialogueHistory>,
+    paused: Res<State<Pause>>,
+) {
+    let mut entries = Vec::new();
+    for (index, session) in history.sessions.iter().enumerate() {
+        if index > 0 {
+            entries.push(("· · ·".to_string(), DISABLED_TEXT));
+        }
+        for entry in &session.entries {
+            entries.push(match entry {
+                DialogueHistoryEntry::Line {
+                    speaker: Some(speaker),
+                    text,
+                } => (format!("{speaker}: {text}"), BODY_TEXT),
+                DialogueHistoryEntry::Line {
+                    speaker: None,
+                    text,
+                } => (text.clone(), BODY_TEXT),
+                DialogueHistoryEntry::Choice(text) => (format!("> {text}"), LABEL_TEXT),
+            });
+        }
+    }
+    if entries.is_empty() {
+        entries.push(("Nothing has been said yet.".to_string(), DISABLED_TEXT));
+    }
+
+    let mut entity_commands = commands.spawn((
+        widget::ui_root("Dialogue Log Screen"),
+        StateScoped(Menu::DialogueLog),
+        GlobalZIndex(2),
+        children![
+            widget::header("Dialogue Log"),
+            (
+                widget::panel(
+                    "Dialogue Log Entries",
+                    Node {
+                        width: Px(900.0),
+                        max_width: Percent(90.0),
+                        height: Percent(65.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Px(8.0),
+                        padding: UiRect::all(Px(20.0)),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                ),
+                // Start at the latest entry. The layout clamps this to the bottom.
+                ScrollPosition {
+                    offset_y: f32::MAX,
+                    ..default()
+                },
+                Children::spawn(SpawnIter(entries.into_iter().map(|(text, color)| {
+                    (
+                        widget::paragraph_with_color(text, color),
+                        // Let the panel underneath receive the mouse wheel.
+                        Pickable::IGNORE,
+                    )
+                }))),
+            ),
+            widget::button("Back", go_back_on_click),
+        ],
+    ));
+    if paused.get() == &Pause(false) {
+        entity_commands.insert(BackgroundColor(SCREEN_BACKGROUND));
+    }
+}
@@ -0,0 +1,176 @@
+//! The inventory menu, listing the items the player carries. Items from the world can be dropped back into it.
+//! It can be opened from the pause menu and during gameplay.
+
+use std::any::Any as _;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    Pause,
+    gameplay::{
+        crosshair::CrosshairState,
+        player::{
+            Player,
+            default_input::{BlocksInput, OpenInventory},
+            inventory::{DropItem, Inventory},
+        },
+    },
+    menus::{Menu, block_gameplay_input, go_back, go_back_on_click, unblock_gameplay_input},
+    theme::{
+        palette::{DISABLED_TEXT, SCREEN_BACKGROUND},
+        prelude::*,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(open_inventory);
+    app.add_systems(
+        OnEnter(Menu::Inventory),
+        (spawn_inventory_menu, block_input),
+    );
+    app.add_systems(OnExit(Menu::Inventory), unblock_input);
+    app.add_systems(
+        Update,
+        (
+            refresh_inventory_menu,
+            go_back.run_if(input_just_pressed(KeyCode::Escape)),
+        )
+            .run_if(in_state(Menu::Inventory)),
+    );
+}
+
+#[derive(Component)]
+struct InventoryMenu;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_inventory(
+    _trigger: Trigger<Started<OpenInventory>>,
+    menu: Res<State<Menu>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    if menu.get() == &Menu::None {
+        next_menu.set(Menu::Inventory);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_inventory_menu(
+    mut commands: Commands,
+    inventory: Single<&Inventory, With<Player>>,
+    paused: Res<State<Pause>>,
+) {
+    spawn_menu(&mut commands, &inventory, &paused);
+}
+
+/// Dropping an item changes the inventory, so we rebuild the list.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn refresh_inventory_menu(
+    mut commands: Commands,
+    inventory: Single<Ref<Inventory>, With<Player>>,
+    menu: Single<Entity, With<InventoryMenu>>,
+    paused: Res<State<Pause>>,
+) {
+    if !inventory.is_changed() {
+        return;
+    }
+    commands.entity(*menu).despawn();
+    spawn_menu(&mut commands, &inventory, &paused);
+}
+
+fn spawn_menu(commands: &mut Commands, inventory: &Inventory, paused: &State<Pause>) {
+    let mut rows = Vec::new();
+    for (id, item) in inventory.iter() {
+        let label = if item.count > 1 {
+            format!("{} x{}", item.name, item.count)
+        } else {
+            item.name.clone()
+        };
+        let id = id.to_string();
+        let droppable = item.model.is_some();
+        rows.push((label, droppable.then_some(id)));
+    }
+
+    let mut entity_commands = commands.spawn((
+        widget::ui_root("Inventory Screen"),
+        InventoryMenu,
+        StateScoped(Menu::Inventory),
+        GlobalZIndex(2),
+    ));
+    entity_commands.with_children(|parent| {
+        parent.spawn(widget::header("Inventory"));
+        parent
+            .spawn(widget::panel(
+                "Inventory Items",
+                Node {
+                    width: Px(700.0),
+                    max_width: Percent(90.0),
+                    max_height: Percent(65.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Px(8.0),
+                    padding: UiRect::all(Px(20.0)),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                if rows.is_empty() {
+                    parent.spawn(widget::paragraph_with_color(
+                        "You are not carrying anything.",
+                        DISABLED_TEXT,
+                    ));
+                }
+                for (label, drop_id) in rows {
+                    let mut row = parent.spawn((
+                        Name::new("Inventory Row"),
+                        Node {
+                            width: Percent(100.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            column_gap: Px(16.0),
+                            ..default()
+                        },
+                    ));
+                    row.with_child(widget::paragraph(label));
+                    if let Some(id) = drop_id {
+                        row.with_child((
+                            Node {
+                                width: Px(120.0),
+                                ..default()
+                            },
+                            children![widget::list_button(
+                                "Drop",
+                                move |_trigger: Trigger<Pointer<Click>>, mut commands: Commands| {
+                                    commands.trigger(DropItem(id.clone()));
+                                },
+                            )],
+                        ));
+                    }
+                }
+            });
+        parent.spawn(widget::button("Back", go_back_on_click));
+    });
+    if paused.get() == &Pause(false) {
+        entity_commands.insert(BackgroundColor(SCREEN_BACKGROUND));
+    }
+}
+
+/// While the inventory is open during gameplay, the player should not walk around and needs a cursor.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn block_input(mut crosshair: Single<&mut CrosshairState>, mut blocks_input: ResMut<BlocksInput>) {
+    block_gameplay_input(block_input.type_id(), &mut crosshair, &mut blocks_input);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn unblock_input(
+    crosshair: Option<Single<&mut CrosshairState>>,
+    mut blocks_input: ResMut<BlocksInput>,
+) {
+    unblock_gameplay_input(
+        block_input.type_id(),
+        crosshair.map(Single::into_inner).as_deref_mut(),
+        &mut blocks_input,
+    );
+}
@@ -0,0 +1,133 @@
+//! The loot menu, showing what is inside the container the player opened, see `props::container`.
+
+use std::any::Any as _;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    gameplay::{crosshair::CrosshairState, player::default_input::BlocksInput},
+    menus::{Menu, block_gameplay_input, unblock_gameplay_input},
+    props::container::{Loot, OpenContainer, TakeLoot},
+    theme::{palette::DISABLED_TEXT, prelude::*},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Loot), (spawn_loot_menu, block_input));
+    app.add_systems(OnExit(Menu::Loot), unblock_input);
+    app.add_systems(
+        Update,
+        (
+            close_when_emptied,
+            close.run_if(input_just_pressed(KeyCode::Escape)),
+        )
+            .run_if(in_state(Menu::Loot)),
+    );
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn spawn_loot_menu(
+    mut commands: Commands,
+    open_container: Res<OpenContainer>,
+    q_loot: Query<&Loot>,
+) {
+    let items = q_loot
+        .get(open_container.0)
+        .map(|loot| loot.items.clone())
+        .unwrap_or_default();
+    commands
+        .spawn((
+            widget::ui_root("Loot Screen"),
+            StateScoped(Menu::Loot),
+            GlobalZIndex(2),
+        ))
+        .with_children(|parent| {
+            parent.spawn(widget::header("Container"));
+            parent
+                .spawn(widget::panel(
+                    "Loot Items",
+                    Node {
+                        width: Px(500.0),
+                        max_width: Percent(90.0),
+                        max_height: Percent(50.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Px(8.0),
+                        padding: UiRect::all(Px(20.0)),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                ))
+                .with_children(|parent| {
+                    if items.is_empty() {
+                        parent.spawn(widget::paragraph_with_color("It's empty.", DISABLED_TEXT));
+                    }
+                    for item in items {
+                        let label = if item.count > 1 {
+                            format!("{} x{}", item.name, item.count)
+                        } else {
+                            item.name
+                        };
+                        parent.spawn(widget::paragraph(label));
+                    }
+                });
+            parent.spawn((
+                Name::new("Loot Buttons"),
+                Node {
+                    column_gap: Px(20.0),
+                    ..default()
+                },
+                children![
+                    widget::button("Take All", take_all),
+                    widget::button("Close", close_on_click),
+                ],
+            ));
+        });
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn take_all(_trigger: Trigger<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(TakeLoot);
+}
+
+/// Taking the loot removes it from the container, after which there is nothing left to show.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn close_when_emptied(
+    open_container: Res<OpenContainer>,
+    q_loot: Query<(), With<Loot>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    if !q_loot.contains(open_container.0) {
+        next_menu.set(Menu::None);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn close_on_click(_trigger: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::None);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn close(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::None);
+}
+
+/// Like the inventory, looting happens during gameplay, so the player should not walk around and needs a cursor.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn block_input(mut crosshair: Single<&mut CrosshairState>, mut blocks_input: ResMut<BlocksInput>) {
+    block_gameplay_input(block_input.type_id(), &mut crosshair, &mut blocks_input);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn unblock_input(
+    crosshair: Option<Single<&mut CrosshairState>>,
+    mut blocks_input: ResMut<BlocksInput>,
+    mut commands: Commands,
+) {
+    commands.remove_resource::<OpenContainer>();
+    unblock_gameplay_input(
+        block_input.type_id(),
+        crosshair.map(Single::into_inner).as_deref_mut(),
+        &mut blocks_input,
+    );
+}
@@ -3,6 +3,7 @@ use bevy::{prelude::*, window::CursorGrabMode};
 
 use crate::{
     menus::Menu,
+    save::SaveGame,
     screens::Screen,
     theme::{palette::SCREEN_BACKGROUND, widget},
 };
@@ -11,26 +12,28 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Main), spawn_main_menu);
 }
 
-fn spawn_main_menu(mut commands: Commands) {
-    commands.spawn((
-        widget::ui_root("Main Menu"),
-        BackgroundColor(SCREEN_BACKGROUND),
-        GlobalZIndex(2),
-        StateScoped(Menu::Main),
-        #[cfg(not(target_family = "wasm"))]
-        children![
-            widget::button("Play", enter_loading_screen),
-            widget::button("Settings", open_settings_menu),
-            widget::button("Credits", open_credits_menu),
-            widget::button("Exit", exit_app),
-        ],
-        #[cfg(target_family = "wasm")]
-        children![
-            widget::button("Play", enter_loading_screen),
-            widget::button("Settings", open_settings_menu),
-            widget::button("Credits", open_credits_menu),
-        ],
-    ));
+fn spawn_main_menu(mut commands: Commands, save_game: Res<SaveGame>) {
+    let has_progress = save_game.has_progress();
+    commands
+        .spawn((
+            widget::ui_root("Main Menu"),
+            BackgroundColor(SCREEN_BACKGROUND),
+            GlobalZIndex(2),
+            StateScoped(Menu::Main),
+        ))
+        .with_children(|parent| {
+            if has_progress {
+                parent.spawn(widget::button("Continue", enter_loading_screen));
+                parent.spawn(widget::button("New Game", start_new_game));
+            } else {
+                // Without progress to continue, throw away whatever else the save game holds.
+                parent.spawn(widget::button("Play", start_new_game));
+            }
+            parent.spawn(widget::button("Settings", open_settings_menu));
+            parent.spawn(widget::button("Credits", open_credits_menu));
+            #[cfg(not(target_family = "wasm"))]
+            parent.spawn(widget::button("Exit", exit_app));
+        });
 }
 
 fn enter_loading_screen(
@@ -42,6 +45,18 @@ fn enter_loading_screen(
     window.cursor_options.grab_mode = CursorGrabMode::Locked;
 }
 
+/// Throw away the saved progress and start from the beginning.
+fn start_new_game(
+    _trigger: Trigger<Pointer<Click>>,
+    mut save_game: ResMut<SaveGame>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    mut window: Single<&mut Window>,
+) {
+    *save_game = default();
+    next_screen.set(Screen::Loading);
+    window.cursor_options.grab_mode = CursorGrabMode::Locked;
+}
+
 fn open_settings_menu(_: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
     next_menu.set(Menu::Settings);
 }
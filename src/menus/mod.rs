@@ -1,20 +1,37 @@
 //! The game's main screen states and transitions between them.
 
 mod credits;
+mod dialogue_log;
+mod inventory;
+mod loot;
 mod main;
 mod pause;
+mod reading;
 mod settings;
 
+use std::any::TypeId;
+
 use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    Pause,
+    gameplay::{crosshair::CrosshairState, player::default_input::BlocksInput},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<Menu>();
 
     app.add_plugins((
         credits::plugin,
+        dialogue_log::plugin,
+        inventory::plugin,
+        loot::plugin,
         main::plugin,
         settings::plugin,
         pause::plugin,
+        reading::plugin,
     ));
 }
 
@@ -28,4 +45,55 @@ pub(crate) enum Menu {
     Credits,
     Settings,
     Pause,
+    DialogueLog,
+    Inventory,
+    Loot,
+    Reading,
+}
+
+/// Menus that are open during gameplay, like the inventory, keep the player from walking around and need a cursor.
+/// `key` is the `type_id()` of the calling system, so that every menu only lifts its own block.
+fn block_gameplay_input(
+    key: TypeId,
+    crosshair: &mut CrosshairState,
+    blocks_input: &mut BlocksInput,
+) {
+    blocks_input.insert(key);
+    crosshair.wants_free_cursor.insert(key);
+}
+
+/// Lifts the block of [`block_gameplay_input`] with the same `key`.
+/// There is no crosshair anymore when the player quits to the title screen.
+fn unblock_gameplay_input(
+    key: TypeId,
+    crosshair: Option<&mut CrosshairState>,
+    blocks_input: &mut BlocksInput,
+) {
+    blocks_input.remove(&key);
+    if let Some(crosshair) = crosshair {
+        crosshair.wants_free_cursor.remove(&key);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn go_back_on_click(
+    _trigger: Trigger<Pointer<Click>>,
+    paused: Res<State<Pause>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    next_menu.set(previous_menu(&paused));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn go_back(paused: Res<State<Pause>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(previous_menu(&paused));
+}
+
+/// Menus like the inventory and the dialogue log are opened either from the pause menu or during gameplay, where no menu is open.
+fn previous_menu(paused: &State<Pause>) -> Menu {
+    if paused.get() == &Pause(true) {
+        Menu::Pause
+    } else {
+        Menu::None
+    }
 }
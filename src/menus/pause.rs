@@ -28,6 +28,8 @@ fn spawn_pause_menu(
             widget::header("Game paused"),
             widget::button("Continue", close_menu),
             widget::button("Settings", open_settings_menu),
+            widget::button("Inventory", open_inventory),
+            widget::button("Dialogue Log", open_dialogue_log),
             widget::button("Quit to title", quit_to_title),
         ],
     ));
@@ -42,6 +44,16 @@ fn open_settings_menu(_trigger: Trigger<Pointer<Click>>, mut next_menu: ResMut<N
     next_menu.set(Menu::Settings);
 }
 
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_inventory(_trigger: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Inventory);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_dialogue_log(_trigger: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::DialogueLog);
+}
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn close_menu(
     _trigger: Trigger<Pointer<Click>>,
@@ -0,0 +1,192 @@
+//! The reading menu, showing the text of a `props::readable::Readable` one page at a time.
+
+use std::any::Any as _;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, ui::Val::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::{
+    gameplay::{crosshair::CrosshairState, player::default_input::BlocksInput},
+    menus::{Menu, block_gameplay_input, unblock_gameplay_input},
+    props::readable::{CurrentReadable, ReadableText},
+    theme::{
+        palette::{DISABLED_TEXT, SCREEN_BACKGROUND},
+        prelude::*,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Menu::Reading), block_input);
+    app.add_systems(OnExit(Menu::Reading), unblock_input);
+    app.add_systems(
+        Update,
+        (
+            (
+                previous_page.run_if(
+                    input_just_pressed(KeyCode::ArrowLeft).or(input_just_pressed(KeyCode::KeyA)),
+                ),
+                next_page.run_if(
+                    input_just_pressed(KeyCode::ArrowRight).or(input_just_pressed(KeyCode::KeyD)),
+                ),
+                close.run_if(input_just_pressed(KeyCode::Escape)),
+            ),
+            refresh_reading_menu,
+        )
+            .chain()
+            .run_if(in_state(Menu::Reading)),
+    );
+}
+
+#[derive(Component)]
+struct ReadingMenu;
+
+/// Turning a page or the text finishing loading rebuilds the whole menu.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn refresh_reading_menu(
+    mut commands: Commands,
+    current: Res<CurrentReadable>,
+    texts: Res<Assets<ReadableText>>,
+    mut asset_events: EventReader<AssetEvent<ReadableText>>,
+    menu: Option<Single<Entity, With<ReadingMenu>>>,
+) {
+    let loaded = asset_events
+        .read()
+        .any(|event| event.is_loaded_with_dependencies(&current.text));
+    if let Some(menu) = menu {
+        if !current.is_changed() && !loaded {
+            return;
+        }
+        commands.entity(*menu).despawn();
+    }
+
+    let text = texts.get(&current.text);
+    let page_count = text.map_or(0, |text| text.pages.len());
+    let page = text.and_then(|text| text.pages.get(current.page));
+    commands
+        .spawn((
+            widget::ui_root("Reading Screen"),
+            ReadingMenu,
+            StateScoped(Menu::Reading),
+            GlobalZIndex(2),
+            BackgroundColor(SCREEN_BACKGROUND),
+        ))
+        .with_children(|parent| {
+            if !current.title.is_empty() {
+                parent.spawn(widget::header(current.title.clone()));
+            }
+            parent
+                .spawn(widget::panel(
+                    "Page",
+                    Node {
+                        width: Px(700.0),
+                        max_width: Percent(90.0),
+                        max_height: Percent(65.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Px(16.0),
+                        padding: UiRect::all(Px(30.0)),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                ))
+                .with_children(|parent| {
+                    let Some(page) = page else {
+                        parent.spawn(widget::paragraph_with_color("...", DISABLED_TEXT));
+                        return;
+                    };
+                    // Blank lines separate paragraphs, like in markdown. Lines starting with `#` are headings.
+                    for block in page.split("\n\n").map(str::trim) {
+                        if block.is_empty() {
+                            continue;
+                        }
+                        if block.starts_with('#') {
+                            parent.spawn(widget::label(block.trim_start_matches('#').trim()));
+                        } else {
+                            let lines: Vec<_> = block.lines().map(str::trim).collect();
+                            parent.spawn(widget::paragraph(lines.join(" ")));
+                        }
+                    }
+                });
+            parent.spawn((
+                Name::new("Page Buttons"),
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Px(20.0),
+                    ..default()
+                },
+                children![
+                    widget::button_small("<", previous_page_on_click),
+                    widget::label(format!("{} / {}", current.page + 1, page_count.max(1))),
+                    widget::button_small(">", next_page_on_click),
+                ],
+            ));
+            parent.spawn(widget::button("Close", close_on_click));
+        });
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn previous_page(mut current: ResMut<CurrentReadable>, texts: Res<Assets<ReadableText>>) {
+    turn_page(&mut current, &texts, -1);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn next_page(mut current: ResMut<CurrentReadable>, texts: Res<Assets<ReadableText>>) {
+    turn_page(&mut current, &texts, 1);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn previous_page_on_click(
+    _trigger: Trigger<Pointer<Click>>,
+    mut current: ResMut<CurrentReadable>,
+    texts: Res<Assets<ReadableText>>,
+) {
+    turn_page(&mut current, &texts, -1);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn next_page_on_click(
+    _trigger: Trigger<Pointer<Click>>,
+    mut current: ResMut<CurrentReadable>,
+    texts: Res<Assets<ReadableText>>,
+) {
+    turn_page(&mut current, &texts, 1);
+}
+
+fn turn_page(current: &mut ResMut<CurrentReadable>, texts: &Assets<ReadableText>, delta: isize) {
+    let page_count = texts.get(&current.text).map_or(0, |text| text.pages.len());
+    let page = current.page.saturating_add_signed(delta);
+    // Avoid triggering a change, and with it a rebuild of the menu, when the page stays the same.
+    if page != current.page && page < page_count {
+        current.page = page;
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn close_on_click(_trigger: Trigger<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::None);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn close(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::None);
+}
+
+/// Like dialogue, reading happens during gameplay, so the player should not walk around and needs a cursor.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn block_input(mut crosshair: Single<&mut CrosshairState>, mut blocks_input: ResMut<BlocksInput>) {
+    block_gameplay_input(block_input.type_id(), &mut crosshair, &mut blocks_input);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn unblock_input(
+    crosshair: Option<Single<&mut CrosshairState>>,
+    mut blocks_input: ResMut<BlocksInput>,
+    mut commands: Commands,
+) {
+    commands.remove_resource::<CurrentReadable>();
+    unblock_gameplay_input(
+        block_input.type_id(),
+        crosshair.map(Single::into_inner).as_deref_mut(),
+        &mut blocks_input,
+    );
+}
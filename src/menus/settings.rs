@@ -9,10 +9,13 @@ use bevy_simple_subsecond_system::hot;
 use crate::{
     Pause,
     audio::{DEFAULT_VOLUME, max_volume},
-    gameplay::player::camera::{CameraSensitivity, WorldModelFov},
+    gameplay::player::{
+        camera::{CameraSensitivity, WorldModelFov},
+        dialogue::view::voice::VoiceOverSettings,
+    },
     menus::Menu,
     screens::Screen,
-    theme::{palette::SCREEN_BACKGROUND, prelude::*},
+    theme::{palette::SCREEN_BACKGROUND, prelude::*, text_scale::TextScale},
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -24,6 +27,8 @@ pub(super) fn plugin(app: &mut App) {
     );
 
     app.register_type::<GlobalVolumeLabel>();
+    app.register_type::<TextScaleLabel>();
+    app.register_type::<AutoAdvanceLabel>();
     app.add_systems(
         Update,
         (
@@ -31,6 +36,8 @@ pub(super) fn plugin(app: &mut App) {
             update_volume_label,
             update_camera_sensitivity_label,
             update_camera_fov_label,
+            update_text_scale_label,
+            update_auto_advance_label,
         )
             .run_if(in_state(Menu::Settings)),
     );
@@ -85,6 +92,28 @@ fn spawn_settings_menu(mut commands: Commands, paused: Res<State<Pause>>) {
                         }
                     ),
                     widget::plus_minus_bar(CameraFovLabel, lower_camera_fov, raise_camera_fov),
+                    // Text Size
+                    (
+                        widget::label("Text Size"),
+                        Node {
+                            justify_self: JustifySelf::End,
+                            ..default()
+                        }
+                    ),
+                    widget::plus_minus_bar(TextScaleLabel, lower_text_scale, raise_text_scale),
+                    // Auto-Advance Voiced Dialogue
+                    (
+                        widget::label("Auto-Advance Voice"),
+                        Node {
+                            justify_self: JustifySelf::End,
+                            ..default()
+                        }
+                    ),
+                    widget::plus_minus_bar(
+                        AutoAdvanceLabel,
+                        disable_auto_advance,
+                        enable_auto_advance
+                    ),
                 ],
             ),
             widget::button("Back", go_back_on_click),
@@ -230,6 +259,56 @@ fn update_camera_fov_label(
     label.0 = format!("{:.1}", camera_fov.0);
 }
 
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct TextScaleLabel;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn lower_text_scale(_trigger: Trigger<Pointer<Click>>, mut text_scale: ResMut<TextScale>) {
+    text_scale.0 = (text_scale.0 - 0.1).max(TextScale::MIN);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn raise_text_scale(_trigger: Trigger<Pointer<Click>>, mut text_scale: ResMut<TextScale>) {
+    text_scale.0 = (text_scale.0 + 0.1).min(TextScale::MAX);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_text_scale_label(
+    mut label: Single<&mut Text, With<TextScaleLabel>>,
+    text_scale: Res<TextScale>,
+) {
+    label.0 = format!("{:.1}", text_scale.0);
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct AutoAdvanceLabel;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn disable_auto_advance(
+    _trigger: Trigger<Pointer<Click>>,
+    mut voice_over: ResMut<VoiceOverSettings>,
+) {
+    voice_over.auto_advance = false;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn enable_auto_advance(
+    _trigger: Trigger<Pointer<Click>>,
+    mut voice_over: ResMut<VoiceOverSettings>,
+) {
+    voice_over.auto_advance = true;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_auto_advance_label(
+    mut label: Single<&mut Text, With<AutoAdvanceLabel>>,
+    voice_over: Res<VoiceOverSettings>,
+) {
+    label.0 = if voice_over.auto_advance { "On" } else { "Off" }.to_string();
+}
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn go_back_on_click(
     _trigger: Trigger<Pointer<Click>>,
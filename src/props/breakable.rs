@@ -0,0 +1,416 @@
+//! Props that break apart when they hit something hard enough. Add [`Breakable`] as a base class to a prop and give it some `health` in TrenchBroom.
+//! A broken prop is replaced by debris of the same [`PhysicsMaterial`] that flies on with the prop's velocity and is cleaned up after a while.
+//! It can leave an item behind. Broken props are part of the save game.
+//!
+//! Breaking a static prop despawns its colliders, so Oxidized Navigation regenerates the navmesh around it on its own.
+
+use avian3d::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+use rand::Rng as _;
+
+use super::{
+    item::{Item, LevelItem},
+    physics_material::PhysicsMaterial,
+};
+use crate::{
+    PostPhysicsAppSystems,
+    save::{SaveGame, level_key},
+    screens::Screen,
+    third_party::avian3d::{CollisionLayer, ReportCollisions, colliding_bodies},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Breakable>();
+    app.register_type::<Durability>();
+    app.register_type::<Debris>();
+    app.init_resource::<DebrisAssets>();
+    app.add_observer(setup_breakable);
+    app.add_observer(break_apart);
+    app.add_systems(
+        Update,
+        (remove_broken_props, damage_breakables, clean_up_debris)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(BaseClass, Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct Breakable {
+    /// How much damage the prop takes before it breaks. Set to 0 for a prop that can't break.
+    pub(crate) health: f32,
+    /// How hard an impact has to be to do any damage, as the change in velocity it causes in m/s.
+    /// That is the impulse per kilogram, so heavy props are as sturdy as light ones. Harder impacts deal the difference as damage.
+    pub(crate) break_impulse: f32,
+    /// The glTF file each piece of debris is displayed with, relative to the assets directory.
+    /// Leave empty to break into plain fragments.
+    pub(crate) debris_model: String,
+    /// How many pieces of debris the prop breaks into.
+    pub(crate) debris_count: u32,
+    /// The ID of the item left behind when the prop breaks, e.g. `cellar_key`. Leave empty to leave nothing behind.
+    pub(crate) item: String,
+    /// The name of the item shown to the player.
+    pub(crate) item_name: String,
+    /// The glTF file the item is displayed with, relative to the assets directory.
+    pub(crate) item_model: String,
+}
+
+impl Default for Breakable {
+    fn default() -> Self {
+        Self {
+            health: 6.0,
+            break_impulse: 4.0,
+            debris_model: String::new(),
+            debris_count: 8,
+            item: String::new(),
+            item_name: String::new(),
+            item_model: String::new(),
+        }
+    }
+}
+
+/// How much damage a [`Breakable`] can still take, along with its [`level_key`].
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+#[require(ReportCollisions)]
+pub(crate) struct Durability {
+    pub(crate) remaining: f32,
+    key: String,
+    /// Where the prop was placed in the level, which identifies the item it leaves behind.
+    origin: Vec3,
+}
+
+/// Trigger this on a [`Breakable`] to break it right away.
+#[derive(Event, Debug, Clone, Copy)]
+pub(crate) struct Break;
+
+/// A [`Breakable`] that was already broken in the save game.
+#[derive(Component, Debug, Clone, Copy)]
+struct Broken;
+
+/// A piece of a broken prop.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+struct Debris {
+    timer: Timer,
+    scale: Vec3,
+}
+
+/// How long debris lies around, in seconds.
+const DEBRIS_LIFETIME: f32 = 10.0;
+/// How long debris takes to shrink away at the end of its lifetime, in seconds.
+const DEBRIS_SHRINK_TIME: f32 = 1.0;
+/// How fast fragments fly apart on top of the prop's own velocity, in m/s.
+const DEBRIS_SCATTER_SPEED: f32 = 1.5;
+const MIN_FRAGMENT_SIZE: f32 = 0.05;
+const MAX_FRAGMENT_SIZE: f32 = 0.3;
+
+/// Debris does not get in the way of characters.
+fn debris_layers() -> CollisionLayers {
+    CollisionLayers::new(
+        CollisionLayer::Prop,
+        [CollisionLayer::Default, CollisionLayer::Prop],
+    )
+}
+
+/// The mesh and materials of plain fragments.
+#[derive(Resource)]
+struct DebrisAssets {
+    mesh: Handle<Mesh>,
+    materials: HashMap<PhysicsMaterial, Handle<StandardMaterial>>,
+}
+
+impl FromWorld for DebrisAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::from_length(1.0));
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let materials = PhysicsMaterial::ALL
+            .into_iter()
+            .map(|physics_material| {
+                let material = materials.add(fragment_material(physics_material));
+                (physics_material, material)
+            })
+            .collect();
+        Self { mesh, materials }
+    }
+}
+
+fn fragment_material(physics_material: PhysicsMaterial) -> StandardMaterial {
+    let material = StandardMaterial {
+        perceptual_roughness: 0.8,
+        ..default()
+    };
+    match physics_material {
+        PhysicsMaterial::Wood => StandardMaterial {
+            base_color: Color::srgb_u8(110, 78, 48),
+            ..material
+        },
+        PhysicsMaterial::Metal => StandardMaterial {
+            base_color: Color::srgb_u8(120, 120, 124),
+            metallic: 0.8,
+            ..material
+        },
+        PhysicsMaterial::Stone => StandardMaterial {
+            base_color: Color::srgb_u8(128, 122, 114),
+            ..material
+        },
+        PhysicsMaterial::Glass => StandardMaterial {
+            base_color: Color::srgba_u8(200, 220, 225, 90),
+            perceptual_roughness: 0.1,
+            alpha_mode: AlphaMode::Blend,
+            ..material
+        },
+        PhysicsMaterial::Cloth => StandardMaterial {
+            base_color: Color::srgb_u8(120, 90, 80),
+            perceptual_roughness: 1.0,
+            ..material
+        },
+        PhysicsMaterial::Cardboard => StandardMaterial {
+            base_color: Color::srgb_u8(176, 140, 96),
+            ..material
+        },
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_breakable(
+    trigger: Trigger<OnAdd, Breakable>,
+    q_breakable: Query<(&Breakable, &Transform)>,
+    save_game: Res<SaveGame>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let (breakable, transform) = q_breakable.get(entity).unwrap();
+    if breakable.health <= 0.0 {
+        return;
+    }
+    let origin = transform.translation;
+    let key = level_key("breakable", origin);
+    if save_game.broken_props.contains(&key) {
+        // The prop's own setup still has to run, so we can't despawn it just yet.
+        commands.entity(entity).insert(Broken);
+        let collected = save_game
+            .collected_items
+            .contains(&level_key(&breakable.item, origin));
+        if !breakable.item.is_empty() && !collected {
+            spawn_item(&mut commands, breakable, origin, origin, Vec3::ZERO);
+        }
+        return;
+    }
+    commands.entity(entity).insert(Durability {
+        remaining: breakable.health,
+        key,
+        origin,
+    });
+}
+
+/// Spawns the item a [`Breakable`] leaves behind. It is saved like an item that was placed where the prop was placed,
+/// so that it stays in the level until the player picks it up.
+fn spawn_item(
+    commands: &mut Commands,
+    breakable: &Breakable,
+    origin: Vec3,
+    position: Vec3,
+    velocity: Vec3,
+) {
+    if breakable.item_model.is_empty() {
+        error!(
+            "Breakable with item \"{}\" has no item model, leaving nothing behind",
+            breakable.item
+        );
+        return;
+    }
+    commands.spawn((
+        Name::new("Breakable Item"),
+        Transform::from_translation(position),
+        LinearVelocity(velocity),
+        LevelItem(level_key(&breakable.item, origin)),
+        Item {
+            item: breakable.item.clone(),
+            name: breakable.item_name.clone(),
+            model: breakable.item_model.clone(),
+        },
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn remove_broken_props(q_broken: Query<Entity, Added<Broken>>, mut commands: Commands) {
+    for entity in &q_broken {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn damage_breakables(
+    mut collision_started: EventReader<CollisionStarted>,
+    collisions: Collisions,
+    q_collider_of: Query<&ColliderOf>,
+    q_body: Query<(&RigidBody, &ComputedMass)>,
+    mut q_breakable: Query<(&Breakable, &mut Durability)>,
+    mut commands: Commands,
+) {
+    for collision in collision_started.read() {
+        let CollisionStarted(collider1, collider2) = collision;
+        let Some(contacts) = collisions.get(*collider1, *collider2) else {
+            continue;
+        };
+        let impulse = contacts.total_normal_impulse_magnitude();
+        let [body1, body2] = colliding_bodies(collision, &q_collider_of);
+        for (body, other) in [(body1, body2), (body2, body1)] {
+            let Ok((breakable, mut durability)) = q_breakable.get_mut(body) else {
+                continue;
+            };
+            if durability.remaining <= 0.0 {
+                // Already breaking.
+                continue;
+            }
+            // A static prop has no mass of its own, so we go by how hard whatever hit it was stopped.
+            let mass = [body, other]
+                .into_iter()
+                .filter_map(|entity| q_body.get(entity).ok())
+                .find(|(rigid_body, _)| rigid_body.is_dynamic())
+                .map(|(_, mass)| mass.value());
+            let Some(mass) = mass else {
+                continue;
+            };
+            let impact_speed = impulse / mass.max(f32::EPSILON);
+            let damage = impact_speed - breakable.break_impulse;
+            if damage <= 0.0 {
+                continue;
+            }
+            durability.remaining -= damage;
+            if durability.remaining <= 0.0 {
+                commands.trigger_targets(Break, body);
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn break_apart(
+    trigger: Trigger<Break>,
+    q_breakable: Query<(
+        &Breakable,
+        &Durability,
+        &GlobalTransform,
+        Option<&LinearVelocity>,
+        Option<&AngularVelocity>,
+        Option<&PhysicsMaterial>,
+        Option<&RigidBodyColliders>,
+    )>,
+    q_aabb: Query<&ColliderAabb>,
+    debris_assets: Res<DebrisAssets>,
+    asset_server: Res<AssetServer>,
+    mut save_game: ResMut<SaveGame>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((breakable, durability, transform, linear, angular, material, colliders)) =
+        q_breakable.get(entity)
+    else {
+        return;
+    };
+    let material = material.copied().unwrap_or_default();
+    let linear = linear.map_or(Vec3::ZERO, |velocity| velocity.0);
+    let angular = angular.map_or(Vec3::ZERO, |velocity| velocity.0);
+    let (min, max) = colliders
+        .into_iter()
+        .flat_map(|colliders| colliders.iter())
+        .filter_map(|collider| q_aabb.get(collider).ok())
+        .fold(None, |bounds: Option<(Vec3, Vec3)>, aabb| {
+            Some(match bounds {
+                Some((min, max)) => (min.min(aabb.min), max.max(aabb.max)),
+                None => (aabb.min, aabb.max),
+            })
+        })
+        .unwrap_or((
+            transform.translation() - Vec3::splat(0.25),
+            transform.translation() + Vec3::splat(0.25),
+        ));
+    let center = (min + max) / 2.0;
+    let size = max - min;
+    let count = breakable.debris_count.max(1);
+    let fragment_size = (size.element_product() / count as f32)
+        .cbrt()
+        .clamp(MIN_FRAGMENT_SIZE, MAX_FRAGMENT_SIZE);
+    let debris_model = (!breakable.debris_model.is_empty()).then(|| {
+        asset_server.load(GltfAssetLabel::Scene(0).from_asset(breakable.debris_model.clone()))
+    });
+    let rng = &mut rand::thread_rng();
+    for _ in 0..count {
+        let position = if count == 1 {
+            center
+        } else {
+            min + size
+                * Vec3::new(
+                    rng.gen_range(0.0..1.0),
+                    rng.gen_range(0.0..1.0),
+                    rng.gen_range(0.0..1.0),
+                )
+        };
+        let scatter = (position - center).normalize_or(Vec3::Y) * DEBRIS_SCATTER_SPEED;
+        let velocity = linear + angular.cross(position - center) + scatter;
+        let scale = match debris_model {
+            Some(_) => Vec3::ONE,
+            None => Vec3::splat(fragment_size * rng.gen_range(0.6..1.2)),
+        };
+        let mut debris = commands.spawn((
+            Name::new("Debris"),
+            Transform::from_translation(position)
+                .with_rotation(transform.rotation())
+                .with_scale(scale),
+            RigidBody::Dynamic,
+            material,
+            LinearVelocity(velocity),
+            AngularVelocity(angular),
+            Debris {
+                timer: Timer::from_seconds(DEBRIS_LIFETIME, TimerMode::Once),
+                scale,
+            },
+            StateScoped(Screen::Gameplay),
+        ));
+        match debris_model.clone() {
+            Some(model) => debris.insert((
+                SceneRoot(model),
+                ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh)
+                    .with_default_layers(debris_layers()),
+            )),
+            None => debris.insert((
+                Mesh3d(debris_assets.mesh.clone()),
+                MeshMaterial3d(debris_assets.materials[&material].clone()),
+                Collider::cuboid(1.0, 1.0, 1.0),
+                debris_layers(),
+            )),
+        };
+    }
+    if !breakable.item.is_empty() {
+        spawn_item(&mut commands, breakable, durability.origin, center, linear);
+    }
+    save_game.broken_props.insert(durability.key.clone());
+    commands.entity(entity).despawn();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn clean_up_debris(
+    time: Res<Time>,
+    mut q_debris: Query<(Entity, &mut Debris, &mut Transform)>,
+    mut commands: Commands,
+) {
+    for (entity, mut debris, mut transform) in &mut q_debris {
+        debris.timer.tick(time.delta());
+        if debris.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let shrink = debris.timer.remaining_secs() / DEBRIS_SHRINK_TIME;
+        if shrink < 1.0 {
+            transform.scale = debris.scale * shrink;
+        }
+    }
+}
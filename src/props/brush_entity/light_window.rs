@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
-use crate::props::effects::disable_shadow_casting;
+use crate::{gameplay::targets::TargetName, props::effects::disable_shadow_casting};
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<LightWindow>();
@@ -12,7 +12,7 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(SolidClass, Component, Debug, Default, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, TargetName)]
 #[spawn_hooks(SpawnHooks::new().convex_collider().smooth_by_default_angle())]
 pub(crate) struct LightWindow;
 
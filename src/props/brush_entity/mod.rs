@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 mod light_window;
+pub(crate) mod room;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins(light_window::plugin);
+    app.add_plugins((light_window::plugin, room::plugin));
 }
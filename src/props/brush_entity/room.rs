@@ -0,0 +1,71 @@
+//! Invisible trigger volumes that mark a named room. We remember which rooms the player has entered, e.g. so that dialogue can react to it.
+
+use avian3d::prelude::*;
+use bevy::{platform::collections::HashSet, prelude::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use crate::{
+    PostPhysicsAppSystems, gameplay::player::Player, screens::Screen,
+    third_party::avian3d::CollisionLayer,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Room>();
+    app.register_type::<VisitedRooms>();
+    app.init_resource::<VisitedRooms>();
+    app.add_observer(setup_room_brush_entity);
+    app.add_systems(OnEnter(Screen::Gameplay), reset_visited_rooms);
+    app.add_systems(
+        Update,
+        update_visited_rooms
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(SolidClass, Component, Debug, Default, Reflect)]
+#[reflect(QuakeClass, Component)]
+#[base(Transform, Visibility)]
+#[spawn_hooks(SpawnHooks::new().convex_collider())]
+#[classname("trigger_room")]
+pub(crate) struct Room {
+    /// The name used to refer to this room, e.g. in `visited_room("cellar")`.
+    pub(crate) room: String,
+}
+
+/// The names of all rooms the player has entered.
+#[derive(Resource, Debug, Clone, Default, Reflect, Deref, DerefMut)]
+#[reflect(Resource)]
+pub(crate) struct VisitedRooms(pub(crate) HashSet<String>);
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_room_brush_entity(trigger: Trigger<OnAdd, Room>, mut commands: Commands) {
+    commands.entity(trigger.target()).insert((
+        Sensor,
+        CollidingEntities::default(),
+        CollisionLayers::new(CollisionLayer::Default, CollisionLayer::Character),
+        // The brushes only mark the volume, so they should not be rendered.
+        Visibility::Hidden,
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn reset_visited_rooms(mut visited_rooms: ResMut<VisitedRooms>) {
+    visited_rooms.clear();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_visited_rooms(
+    q_room: Query<(&Room, &CollidingEntities)>,
+    player: Single<Entity, With<Player>>,
+    mut visited_rooms: ResMut<VisitedRooms>,
+) {
+    for (room, colliding_entities) in &q_room {
+        if colliding_entities.contains(&*player) && !visited_rooms.contains(&room.room) {
+            info!("Player entered room \"{}\" for the first time.", room.room);
+            visited_rooms.insert(room.room.clone());
+        }
+    }
+}
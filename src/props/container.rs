@@ -0,0 +1,200 @@
+//! Containers like barrels and crates that hold loot. Set `loot` in TrenchBroom to make a container usable.
+//! Using a container opens it, optionally swapping to a model with its lid open, and shows what is inside in the loot menu.
+//! Whether a container was opened and whether it was emptied is part of the save game.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::{
+        interaction::{Interactable, Interacted, InteractionAction},
+        player::{Player, inventory::Inventory},
+    },
+    menus::Menu,
+    save::{SaveGame, level_key},
+    screens::Screen,
+    third_party::avian3d::CollisionLayer,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Container>();
+    app.register_type::<Loot>();
+    app.register_type::<Opened>();
+    app.add_observer(setup_container);
+    app.add_observer(open_container);
+    app.add_observer(take_loot);
+    app.add_systems(
+        Update,
+        show_open_model
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(BaseClass, Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct Container {
+    /// The items inside, separated by `;`. Each is written as `id`, `id:Name` or `id:Name:count`,
+    /// e.g. `cellar_key:Cellar Key; coin:Gold Coin:3`. Leave empty for a container that can't be opened.
+    pub(crate) loot: String,
+    /// The glTF file to show once the container is open, relative to the assets directory. Leave empty to keep the model.
+    pub(crate) open_model: String,
+}
+
+/// What is still inside a [`Container`], along with its [`level_key`].
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Loot {
+    key: String,
+    pub(crate) items: Vec<LootItem>,
+}
+
+#[derive(Debug, Clone, Reflect, PartialEq, Eq)]
+pub(crate) struct LootItem {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) count: u32,
+}
+
+/// Marks a [`Container`] that was opened.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct Opened;
+
+/// The container whose loot is shown in the loot menu.
+#[derive(Resource, Debug, Clone, Copy)]
+pub(crate) struct OpenContainer(pub(crate) Entity);
+
+/// Trigger this to move everything in the [`OpenContainer`] into the player's inventory.
+#[derive(Event, Debug, Clone, Copy)]
+pub(crate) struct TakeLoot;
+
+fn parse_loot(loot: &str) -> Vec<LootItem> {
+    loot.split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let mut parts = entry.split(':').map(str::trim);
+            let id = parts.next()?.to_string();
+            let name = parts.next().unwrap_or(&id).to_string();
+            let count = match parts.next() {
+                Some(count) => match count.parse() {
+                    Ok(0) => {
+                        warn!("Loot \"{id}\" has a count of 0");
+                        return None;
+                    }
+                    Ok(count) => count,
+                    Err(err) => {
+                        warn!("Invalid count \"{count}\" for loot \"{id}\": {err}");
+                        return None;
+                    }
+                },
+                None => 1,
+            };
+            Some(LootItem { id, name, count })
+        })
+        .collect()
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_container(
+    trigger: Trigger<OnAdd, Container>,
+    q_container: Query<(&Container, &Transform)>,
+    save_game: Res<SaveGame>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let (container, transform) = q_container.get(entity).unwrap();
+    if container.loot.is_empty() {
+        return;
+    }
+    let key = level_key("container", transform.translation);
+    let opened = save_game.opened_containers.contains(&key);
+    if opened {
+        commands.entity(entity).insert(Opened);
+    }
+    if save_game.looted_containers.contains(&key) {
+        return;
+    }
+    let prompt = if opened { "Search" } else { "Open" };
+    commands.entity(entity).insert((
+        Loot {
+            items: parse_loot(&container.loot),
+            key,
+        },
+        Interactable::new(prompt, InteractionAction::Open),
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_container(
+    trigger: Trigger<Interacted>,
+    q_loot: Query<&Loot>,
+    mut save_game: ResMut<SaveGame>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut commands: Commands,
+) {
+    if trigger.event().0 != InteractionAction::Open {
+        return;
+    }
+    let entity = trigger.target();
+    let Ok(loot) = q_loot.get(entity) else {
+        return;
+    };
+    save_game.opened_containers.insert(loot.key.clone());
+    commands
+        .entity(entity)
+        .insert((Opened, Interactable::new("Search", InteractionAction::Open)));
+    commands.insert_resource(OpenContainer(entity));
+    next_menu.set(Menu::Loot);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn take_loot(
+    _trigger: Trigger<TakeLoot>,
+    open_container: Res<OpenContainer>,
+    q_loot: Query<&Loot>,
+    mut inventory: Single<&mut Inventory, With<Player>>,
+    mut save_game: ResMut<SaveGame>,
+    mut commands: Commands,
+) {
+    let Ok(loot) = q_loot.get(open_container.0) else {
+        return;
+    };
+    for item in &loot.items {
+        inventory.add_named(&item.id, &item.name, item.count);
+    }
+    save_game.looted_containers.insert(loot.key.clone());
+    commands
+        .entity(open_container.0)
+        .remove::<(Loot, Interactable)>();
+}
+
+/// This runs after the prop's own setup has inserted the closed model, so that the open one wins.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn show_open_model(
+    q_container: Query<(Entity, &Container), Added<Opened>>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    for (entity, container) in &q_container {
+        if container.open_model.is_empty() {
+            continue;
+        }
+        let model =
+            asset_server.load(GltfAssetLabel::Scene(0).from_asset(container.open_model.clone()));
+        // The colliders belong to the meshes of the old scene, so we need to build them again.
+        commands.entity(entity).insert((
+            SceneRoot(model),
+            ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh)
+                .with_default_layers(CollisionLayers::new(
+                    CollisionLayer::Default,
+                    LayerMask::ALL,
+                )),
+        ));
+    }
+}
@@ -1,132 +1,103 @@
-use super::setup::*;
+//! Generic props are props that need no code of their own. They are declared in `props.ron` next to this file,
+//! which `build.rs` turns into one TrenchBroom point class per prop. Each class requires a [`GenericProp`] describing how to set it up.
+
+use super::{
+    breakable::Breakable,
+    container::Container,
+    physics_material::{PhysicsMaterial, PhysicsMaterialOverride},
+    setup::{dynamic_bundle_from_scene, static_bundle_from_scene},
+};
+use crate::{
+    gameplay::interaction::{lock::Lock, nameplate::NameplateLabel},
+    third_party::bevy_landmass::NavMeshAffectorParent,
+};
+use avian_pickup::prop::PreferredPickupRotation;
+use avian3d::prelude::*;
 use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_observer(setup_static_prop_with_convex_hull::<Grate>)
-        .add_observer(setup_static_prop_with_convex_decomposition::<Table>)
-        .add_observer(setup_static_prop_with_convex_hull::<Bookshelf>)
-        .add_observer(setup_static_prop_with_convex_hull::<Generator2>)
-        .add_observer(setup_static_prop_with_convex_hull::<BarrelLargeClosed>)
-        .add_observer(setup_static_prop_with_convex_hull::<Barrel01>)
-        .add_observer(setup_static_prop_with_convex_hull::<CrateSquare>)
-        .add_observer(setup_static_prop_with_convex_hull::<FenceBarsDecorativeSingle>)
-        .add_observer(setup_static_prop_with_convex_hull::<DoorStainedGlass>);
-
-    app.add_observer(setup_dynamic_prop_with_convex_hull::<PackageMedium>)
-        .add_observer(setup_dynamic_prop_with_convex_hull::<PackageSmall>);
-
-    app.add_observer(setup_nonphysical_prop::<IvyPart8>)
-        .add_observer(setup_nonphysical_prop::<SmallDoorSign1>);
-
-    app.register_type::<Grate>();
-    app.register_type::<Table>();
-    app.register_type::<Bookshelf>();
-    app.register_type::<Generator2>();
-    app.register_type::<BarrelLargeClosed>();
-    app.register_type::<Barrel01>();
-    app.register_type::<CrateSquare>();
-    app.register_type::<FenceBarsDecorativeSingle>();
-    app.register_type::<PackageMedium>();
-    app.register_type::<PackageSmall>();
-    app.register_type::<DoorStainedGlass>();
-    app.register_type::<IvyPart8>();
-    app.register_type::<SmallDoorSign1>();
+    app.register_type::<GenericProp>();
+    app.add_observer(setup_generic_prop);
+    register_generic_props(app);
 }
 
-// generic dynamic props
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/containers/package_medium.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct PackageMedium;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/containers/package_small.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct PackageSmall;
-
-// generic static props
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/fireplace/grate.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct Grate;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/furniture/tables/rtable1.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct Table;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/furniture/shelves/bookshelf02.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct Bookshelf;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/mechanical/generator2/generator2.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct Generator2;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/containers/barrel_large_closed.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct BarrelLargeClosed;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/containers/barrel01.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct Barrel01;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/containers/crate_square.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct CrateSquare;
-
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/architecture/fencing/fence_bars_decorative01_single.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct FenceBarsDecorativeSingle;
+include!(concat!(env!("OUT_DIR"), "/generic_props.rs"));
+
+/// How a generic prop is set up, as declared in `props.ron`.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct GenericProp {
+    model: &'static str,
+    physics: PropPhysics,
+    collider: PropCollider,
+    density: Option<f32>,
+    pickup_rotation: Option<Quat>,
+    navmesh_affector: bool,
+}
 
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/architecture/doors/door_stained_glass_118x52.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct DoorStainedGlass;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+enum PropPhysics {
+    /// Unmovable terrain.
+    Static,
+    /// Influenced by physics.
+    Dynamic,
+    /// No collision at all.
+    Nonphysical,
+}
 
-// Generic non-physical props
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+enum PropCollider {
+    ConvexHull,
+    ConvexDecomposition,
+    Trimesh,
+}
 
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/nature/ivy_part08.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct IvyPart8;
+impl PropCollider {
+    fn constructor(self) -> ColliderConstructor {
+        match self {
+            Self::ConvexHull => ColliderConstructor::ConvexHullFromMesh,
+            Self::ConvexDecomposition => ColliderConstructor::ConvexDecompositionFromMesh,
+            Self::Trimesh => ColliderConstructor::TrimeshFromMesh,
+        }
+    }
+}
 
-#[derive(PointClass, Component, Debug, Reflect)]
-#[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
-#[model("models/darkmod/decorative/signs/small_door_sign1.gltf")]
-#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct SmallDoorSign1;
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_generic_prop(
+    trigger: Trigger<OnAdd, GenericProp>,
+    q_prop: Query<&GenericProp>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let prop = q_prop.get(entity).unwrap();
+    let model = asset_server.load(GltfAssetLabel::Scene(0).from_asset(prop.model));
+    let constructor = prop.collider.constructor();
+    let mut entity_commands = commands.entity(entity);
+    match prop.physics {
+        PropPhysics::Static => {
+            entity_commands.insert(static_bundle_from_scene(model, constructor));
+        }
+        PropPhysics::Dynamic => {
+            entity_commands.insert(dynamic_bundle_from_scene(model, constructor));
+        }
+        PropPhysics::Nonphysical => {
+            entity_commands.insert(SceneRoot(model));
+        }
+    }
+    if prop.navmesh_affector {
+        entity_commands.insert(NavMeshAffectorParent);
+    } else {
+        entity_commands.remove::<NavMeshAffectorParent>();
+    }
+    if let Some(density) = prop.density {
+        // Takes precedence over the density of the prop's physics material.
+        entity_commands.insert(ColliderDensity(density));
+    }
+    if let Some(rotation) = prop.pickup_rotation {
+        entity_commands.insert(PreferredPickupRotation(rotation));
+    }
+}
@@ -0,0 +1,150 @@
+//! Props make a sound when they bump into something. The sound depends on the [`ImpactSurface`] of the prop's [`PhysicsMaterial`],
+//! and its loudness on how hard the prop was hit. Light touches and props coming to rest make no sound.
+
+use avian3d::prelude::*;
+use bevy::{audio::Volume, prelude::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use rand::Rng as _;
+
+use super::physics_material::PhysicsMaterial;
+use crate::{
+    PostPhysicsAppSystems,
+    asset_tracking::LoadResource as _,
+    audio::{PROP_SPATIAL_SCALE, SoundEffect},
+    screens::Screen,
+    third_party::avian3d::colliding_bodies,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.load_resource::<ImpactSoundAssets>();
+    app.add_systems(
+        Update,
+        play_impact_sounds
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::PlaySounds),
+    );
+}
+
+/// What a prop sounds like when it hits something. Only props with a [`PhysicsMaterial`] make impact sounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ImpactSurface {
+    #[default]
+    Wood,
+    Cardboard,
+    Metal,
+    Stone,
+    Glass,
+    Cloth,
+}
+
+#[derive(Resource, Asset, Clone, TypePath)]
+struct ImpactSoundAssets {
+    #[dependency]
+    wood: Handle<AudioSource>,
+    #[dependency]
+    cardboard: Handle<AudioSource>,
+    #[dependency]
+    metal: Handle<AudioSource>,
+    #[dependency]
+    stone: Handle<AudioSource>,
+    #[dependency]
+    glass: Handle<AudioSource>,
+    #[dependency]
+    cloth: Handle<AudioSource>,
+}
+
+impl ImpactSoundAssets {
+    fn get(&self, surface: ImpactSurface) -> Handle<AudioSource> {
+        match surface {
+            ImpactSurface::Wood => self.wood.clone(),
+            ImpactSurface::Cardboard => self.cardboard.clone(),
+            ImpactSurface::Metal => self.metal.clone(),
+            ImpactSurface::Stone => self.stone.clone(),
+            ImpactSurface::Glass => self.glass.clone(),
+            ImpactSurface::Cloth => self.cloth.clone(),
+        }
+    }
+}
+
+impl FromWorld for ImpactSoundAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            wood: assets.load("audio/sound_effects/impact/wood.ogg"),
+            cardboard: assets.load("audio/sound_effects/impact/cardboard.ogg"),
+            metal: assets.load("audio/sound_effects/impact/metal.ogg"),
+            stone: assets.load("audio/sound_effects/impact/stone.ogg"),
+            glass: assets.load("audio/sound_effects/impact/glass.ogg"),
+            cloth: assets.load("audio/sound_effects/impact/cloth.ogg"),
+        }
+    }
+}
+
+/// Impacts that change the prop's velocity by less than this, in m/s, are silent.
+const MIN_IMPACT_SPEED: f32 = 0.6;
+/// Impacts that change the prop's velocity by this much, in m/s, play at full volume.
+const LOUD_IMPACT_SPEED: f32 = 6.0;
+/// The mass in kg at which a prop sounds like its recording. Heavier props are louder and deeper.
+const REFERENCE_MASS: f32 = 10.0;
+/// How long a prop stays silent after making a sound, in seconds. Keeps tumbling props from rattling.
+const IMPACT_SOUND_COOLDOWN: f32 = 0.15;
+
+/// When the prop last made an impact sound, in seconds since startup.
+#[derive(Component, Debug, Clone, Copy)]
+struct LastImpactSound(f32);
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn play_impact_sounds(
+    mut collision_started: EventReader<CollisionStarted>,
+    collisions: Collisions,
+    q_collider_of: Query<&ColliderOf>,
+    q_body: Query<(
+        &PhysicsMaterial,
+        &ComputedMass,
+        &GlobalTransform,
+        Option<&LastImpactSound>,
+    )>,
+    impact_sounds: Res<ImpactSoundAssets>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let now = time.elapsed_secs();
+    for collision in collision_started.read() {
+        let CollisionStarted(collider1, collider2) = collision;
+        let Some(contacts) = collisions.get(*collider1, *collider2) else {
+            continue;
+        };
+        let impulse = contacts.total_normal_impulse_magnitude();
+        for body in colliding_bodies(collision, &q_collider_of) {
+            let Ok((material, mass, transform, last_impact)) = q_body.get(body) else {
+                continue;
+            };
+            if last_impact.is_some_and(|last| now - last.0 < IMPACT_SOUND_COOLDOWN) {
+                continue;
+            }
+            let mass = mass.value().max(f32::EPSILON);
+            let impact_speed = impulse / mass;
+            if impact_speed < MIN_IMPACT_SPEED {
+                continue;
+            }
+            let heaviness = (mass / REFERENCE_MASS).powf(0.25).clamp(0.5, 1.5);
+            let volume = (impact_speed / LOUD_IMPACT_SPEED).min(1.0) * heaviness;
+            let rng = &mut rand::thread_rng();
+            let speed = rng.gen_range(0.9..1.1) / heaviness.sqrt();
+            commands.entity(body).insert(LastImpactSound(now));
+            commands.spawn((
+                Name::new("Impact Sound"),
+                Transform::from_translation(transform.translation()),
+                AudioPlayer(impact_sounds.get(material.impact_surface())),
+                PlaybackSettings::DESPAWN
+                    .with_spatial(true)
+                    .with_spatial_scale(PROP_SPATIAL_SCALE)
+                    .with_volume(Volume::Linear(volume))
+                    .with_speed(speed),
+                SoundEffect,
+                StateScoped(Screen::Gameplay),
+            ));
+        }
+    }
+}
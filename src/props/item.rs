@@ -0,0 +1,74 @@
+//! Items are props the player can put into their inventory, see `gameplay::player::inventory`.
+//! Any model can be an item. Items placed in the level remember whether they were already taken, so that loading a save
+//! does not bring them back.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use super::{
+    physics_material::{PhysicsMaterial, PhysicsMaterialOverride},
+    setup::dynamic_bundle_from_scene,
+};
+use crate::{
+    gameplay::interaction::{Interactable, InteractionAction, nameplate::NameplateLabel},
+    save::{SaveGame, level_key},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Item>();
+    app.register_type::<LevelItem>();
+    app.register_type::<DroppedItem>();
+    app.add_observer(setup_item);
+}
+
+#[derive(PointClass, Component, Debug, Clone, Default, Reflect, PartialEq, Eq)]
+#[reflect(QuakeClass, Component, Default, Debug)]
+#[base(Transform, Visibility, PhysicsMaterialOverride, NameplateLabel)]
+#[require(PhysicsMaterial)]
+pub(crate) struct Item {
+    /// The ID that dialogue and doors use to refer to this item, e.g. `cellar_key`.
+    pub(crate) item: String,
+    /// The name shown to the player.
+    pub(crate) name: String,
+    /// The glTF file the item is displayed with, relative to the assets directory.
+    pub(crate) model: String,
+}
+
+/// An [`Item`] that was placed in the level, identified by its [`level_key`].
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct LevelItem(pub(crate) String);
+
+/// An [`Item`] the player dropped from their inventory.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct DroppedItem;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_item(
+    trigger: Trigger<OnAdd, Item>,
+    q_item: Query<(&Item, &Transform, Has<DroppedItem>, Option<&LevelItem>)>,
+    save_game: Res<SaveGame>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let (item, transform, dropped, level_item) = q_item.get(entity).unwrap();
+    // Items spawned by something in the level, like a broken prop, already come with their key.
+    if !dropped && level_item.is_none() {
+        let key = level_key(&item.item, transform.translation);
+        if save_game.collected_items.contains(&key) {
+            commands.entity(entity).despawn();
+            return;
+        }
+        commands.entity(entity).insert(LevelItem(key));
+    }
+    let model = asset_server.load(GltfAssetLabel::Scene(0).from_asset(item.model.clone()));
+    commands.entity(entity).insert((
+        dynamic_bundle_from_scene(model, ColliderConstructor::ConvexHullFromMesh),
+        Interactable::new(format!("Take {}", item.name), InteractionAction::PickUp),
+    ));
+}
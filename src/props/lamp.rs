@@ -0,0 +1,278 @@
+//! Lamps that can be switched on and off. Add [`Lamp`] as a base class to a prop and give its light a [`LampLight`].
+//! The player switches a lamp by using it, and firing its `targetname` switches it as well.
+//! Lamps in the same `group` always switch together. Switching fades the light and swaps emissive materials for unlit ones.
+//! Whether a lamp is on is part of the save game. Lamps can be animated with a [`LightStyle`](super::light_style::LightStyle).
+
+use bevy::{
+    ecs::system::SystemParam, platform::collections::HashMap, prelude::*, scene::SceneInstanceReady,
+};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::{
+        interaction::{Interactable, Interacted, InteractionAction},
+        targets::Activate,
+    },
+    props::light_style::UnstyledIntensity,
+    save::{SaveGame, level_key},
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Lamp>();
+    app.register_type::<LampLight>();
+    app.init_resource::<UnlitMaterials>();
+    app.add_observer(setup_lamp);
+    app.add_observer(prepare_emissive_materials);
+    app.add_observer(toggle_lamp_on_interaction);
+    app.add_observer(toggle_lamp_on_activation);
+    app.add_systems(
+        Update,
+        (fade_lamp_lights, swap_emissive_materials)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(BaseClass, Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct Lamp {
+    /// Whether the lamp is switched on.
+    pub(crate) on: bool,
+    /// Lamps with the same group switch together. Leave empty for a lamp that switches on its own.
+    pub(crate) group: String,
+}
+
+impl Default for Lamp {
+    fn default() -> Self {
+        Self {
+            on: true,
+            group: String::new(),
+        }
+    }
+}
+
+/// A light that belongs to the [`Lamp`] it is a child of. Holds the light's intensity while the lamp is on.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct LampLight(pub(crate) f32);
+
+/// The [`level_key`] of a [`Lamp`].
+#[derive(Component, Debug, Clone)]
+struct LampKey(String);
+
+/// A mesh of a [`Lamp`] that glows while the lamp is on.
+#[derive(Component, Debug, Clone)]
+struct EmissiveSwap {
+    lit: Handle<StandardMaterial>,
+    unlit: Handle<StandardMaterial>,
+}
+
+/// The unlit versions of emissive materials, so that lamps sharing a material also share its unlit version.
+#[derive(Resource, Debug, Default)]
+struct UnlitMaterials(HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>);
+
+/// How long a light takes to fade in or out, in seconds.
+const LIGHT_FADE_TIME: f32 = 0.3;
+
+impl Lamp {
+    fn prompt(&self) -> &'static str {
+        if self.on { "Switch off" } else { "Switch on" }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_lamp(
+    trigger: Trigger<OnAdd, Lamp>,
+    mut q_lamp: Query<(&mut Lamp, &Transform)>,
+    save_game: Res<SaveGame>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let (mut lamp, transform) = q_lamp.get_mut(entity).unwrap();
+    let key = level_key("lamp", transform.translation);
+    if let Some(&on) = save_game.lamps.get(&key) {
+        lamp.on = on;
+    }
+    commands.entity(entity).insert((
+        LampKey(key),
+        Interactable::new(lamp.prompt(), InteractionAction::Toggle),
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn toggle_lamp_on_interaction(trigger: Trigger<Interacted>, mut lamps: Lamps) {
+    if trigger.event().0 != InteractionAction::Toggle {
+        return;
+    }
+    lamps.toggle_lamp(trigger.target());
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn toggle_lamp_on_activation(trigger: Trigger<Activate>, mut lamps: Lamps) {
+    lamps.toggle_lamp(trigger.target());
+}
+
+/// Switches lamps on and off.
+#[derive(SystemParam)]
+pub(crate) struct Lamps<'w, 's> {
+    q_lamp: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut Lamp,
+            &'static LampKey,
+            &'static mut Interactable,
+        ),
+    >,
+    save_game: ResMut<'w, SaveGame>,
+}
+
+impl Lamps<'_, '_> {
+    /// Whether the entity is a [`Lamp`].
+    pub(crate) fn contains(&self, entity: Entity) -> bool {
+        self.q_lamp.contains(entity)
+    }
+
+    /// Switches the lamp and every other lamp in its group on or off.
+    pub(crate) fn set_lamp(&mut self, entity: Entity, on: bool) {
+        let Ok((_, lamp, ..)) = self.q_lamp.get(entity) else {
+            return;
+        };
+        let group = lamp.group.clone();
+        for (other, mut lamp, key, mut interactable) in &mut self.q_lamp {
+            let switches = other == entity || (!group.is_empty() && lamp.group == group);
+            if !switches {
+                continue;
+            }
+            lamp.on = on;
+            interactable.prompt = lamp.prompt().to_string();
+            self.save_game.lamps.insert(key.0.clone(), on);
+        }
+    }
+
+    /// Switches the lamp and every other lamp in its group.
+    pub(crate) fn toggle_lamp(&mut self, entity: Entity) {
+        let Ok((_, lamp, ..)) = self.q_lamp.get(entity) else {
+            return;
+        };
+        let on = !lamp.on;
+        self.set_lamp(entity, on);
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn fade_lamp_lights(
+    time: Res<Time>,
+    q_lamp: Query<&Lamp>,
+    mut q_light: Query<(
+        Ref<LampLight>,
+        &ChildOf,
+        Option<Mut<UnstyledIntensity>>,
+        Option<&mut PointLight>,
+        Option<&mut SpotLight>,
+    )>,
+) {
+    for (lamp_light, child_of, unstyled, point_light, spot_light) in &mut q_light {
+        let Ok(lamp) = q_lamp.get(child_of.parent()) else {
+            continue;
+        };
+        let target = if lamp.on { lamp_light.0 } else { 0.0 };
+        let step = lamp_light.0 / LIGHT_FADE_TIME * time.delta_secs();
+        // Lamps that start out switched off should not fade out when the level is loaded.
+        let snap = lamp_light.is_added() || unstyled.as_ref().is_some_and(|u| u.is_added());
+        let fade = |intensity: f32| {
+            if snap {
+                target
+            } else {
+                intensity + (target - intensity).clamp(-step, step)
+            }
+        };
+        // A styled light gets its intensity from its style, so we fade the intensity the style is applied to.
+        if let Some(mut unstyled) = unstyled {
+            if unstyled.0 != target {
+                unstyled.0 = fade(unstyled.0);
+            }
+            continue;
+        }
+        if let Some(mut light) = point_light {
+            if light.intensity != target {
+                light.intensity = fade(light.intensity);
+            }
+        }
+        if let Some(mut light) = spot_light {
+            if light.intensity != target {
+                light.intensity = fade(light.intensity);
+            }
+        }
+    }
+}
+
+/// Remembers the lit and unlit versions of every emissive material in the lamp's model.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn prepare_emissive_materials(
+    trigger: Trigger<SceneInstanceReady>,
+    q_lamp: Query<(), With<Lamp>>,
+    q_children: Query<&Children>,
+    q_mesh_material: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut unlit_materials: ResMut<UnlitMaterials>,
+    mut commands: Commands,
+) {
+    let lamp = trigger.target();
+    if !q_lamp.contains(lamp) {
+        return;
+    }
+    for descendant in q_children.iter_descendants(lamp) {
+        let Ok(mesh_material) = q_mesh_material.get(descendant) else {
+            continue;
+        };
+        let lit = mesh_material.0.clone();
+        let unlit = match unlit_materials.0.get(&lit.id()) {
+            Some(unlit) => unlit.clone(),
+            None => {
+                let Some(material) = materials.get(&lit) else {
+                    continue;
+                };
+                if material.emissive == LinearRgba::BLACK {
+                    continue;
+                }
+                let unlit = materials.add(StandardMaterial {
+                    emissive: LinearRgba::BLACK,
+                    ..material.clone()
+                });
+                unlit_materials.0.insert(lit.id(), unlit.clone());
+                unlit
+            }
+        };
+        commands
+            .entity(descendant)
+            .insert(EmissiveSwap { lit, unlit });
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn swap_emissive_materials(
+    q_lamp: Query<(Entity, Ref<Lamp>)>,
+    q_children: Query<&Children>,
+    mut q_mesh: Query<(Ref<EmissiveSwap>, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, lamp) in &q_lamp {
+        for descendant in q_children.iter_descendants(entity) {
+            let Ok((swap, mut mesh_material)) = q_mesh.get_mut(descendant) else {
+                continue;
+            };
+            if !lamp.is_changed() && !swap.is_added() {
+                continue;
+            }
+            let material = if lamp.on { &swap.lit } else { &swap.unlit };
+            if mesh_material.0 != *material {
+                mesh_material.0 = material.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Quake-style light animation. A [`LightStyle`] is a pattern of letters from `a` (dark) through `m` (normal brightness)
+//! to `z` (double brightness) that is played back at a fixed rate, one letter after the other.
+//!
+//! The style animates the light it is on, as well as lights that are direct children of it,
+//! so that it can be set on a light in TrenchBroom as well as on a prop that spawns its own light, like a lamp.
+//!
+//! Some of the styles that shipped with Quake:
+//!
+//! | Style             | Pattern                                               |
+//! |-------------------|-------------------------------------------------------|
+//! | Flicker           | `mmnmmommommnonmmonqnmmo`                             |
+//! | Slow strong pulse | `abcdefghijklmnopqrstuvwxyzyxwvutsrqponmlkjihgfedcba` |
+//! | Candle            | `mmmmmaaaaammmmmaaaaaabcdefgabcdefg`                  |
+//! | Fast strobe       | `mamamamamama`                                        |
+//! | Gentle pulse      | `jklmnopqrstuvwxyzyxwvutsrqponmlkj`                   |
+//! | Slow strobe       | `aaaaaaaazzzzzzzz`                                    |
+//! | Fluorescent       | `mmamammmmammamamaaamammma`                           |
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use crate::{PostPhysicsAppSystems, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<LightStyle>();
+    app.register_type::<UnstyledIntensity>();
+    app.add_observer(validate_light_style);
+    app.add_systems(
+        Update,
+        (track_unstyled_intensity, animate_light_styles)
+            .chain()
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(BaseClass, Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct LightStyle {
+    /// Letters from `a` (dark) over `m` (normal) to `z` (double brightness), e.g. `mmnmmommommnonmmonqnmmo`.
+    /// Leave empty for a steady light.
+    pub(crate) light_style: String,
+    /// How many letters of the style are played per second.
+    pub(crate) light_style_rate: f32,
+}
+
+impl Default for LightStyle {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+/// The intensity of a styled light before its [`LightStyle`] is applied.
+/// Whatever wants to change the brightness of a styled light, like a lamp being switched off, changes this instead.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct UnstyledIntensity(pub(crate) f32);
+
+impl LightStyle {
+    /// How many letters Quake plays per second.
+    pub(crate) const DEFAULT_RATE: f32 = 10.0;
+
+    /// The flicker of a torch or fire.
+    pub(crate) const FLICKER: &str = "mmnmmommommnonmmonqnmmo";
+
+    /// A style played at the [`DEFAULT_RATE`](Self::DEFAULT_RATE).
+    pub(crate) fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            light_style: pattern.into(),
+            light_style_rate: Self::DEFAULT_RATE,
+        }
+    }
+
+    /// How bright the light is at the given time, where `m` is 1.0.
+    pub(crate) fn brightness(&self, elapsed_secs: f32) -> f32 {
+        let pattern = self.light_style.as_bytes();
+        if pattern.is_empty() {
+            return 1.0;
+        }
+        let index = (elapsed_secs * self.light_style_rate.max(0.0)) as usize % pattern.len();
+        let letter = pattern[index].to_ascii_lowercase().clamp(b'a', b'z');
+        f32::from(letter - b'a') / f32::from(b'm' - b'a')
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn validate_light_style(trigger: Trigger<OnAdd, LightStyle>, q_style: Query<&LightStyle>) {
+    let style = &q_style.get(trigger.target()).unwrap().light_style;
+    if !style.chars().all(|letter| letter.is_ascii_alphabetic()) {
+        warn!("Light style \"{style}\" should only contain the letters a to z");
+    }
+}
+
+/// Remembers the intensity of every light a [`LightStyle`] applies to before the style changes it.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn track_unstyled_intensity(
+    q_light: Query<
+        (
+            Entity,
+            Option<&ChildOf>,
+            Option<&PointLight>,
+            Option<&SpotLight>,
+        ),
+        (
+            Without<UnstyledIntensity>,
+            Or<(With<PointLight>, With<SpotLight>)>,
+        ),
+    >,
+    q_style: Query<(), With<LightStyle>>,
+    mut commands: Commands,
+) {
+    for (entity, child_of, point_light, spot_light) in &q_light {
+        let styled = q_style.contains(entity)
+            || child_of.is_some_and(|child_of| q_style.contains(child_of.parent()));
+        if !styled {
+            continue;
+        }
+        let intensity = point_light
+            .map(|light| light.intensity)
+            .or(spot_light.map(|light| light.intensity))
+            .unwrap_or_default();
+        commands.entity(entity).insert(UnstyledIntensity(intensity));
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn animate_light_styles(
+    time: Res<Time>,
+    q_style: Query<(Entity, &LightStyle, Option<&Children>)>,
+    mut q_light: Query<(
+        &UnstyledIntensity,
+        Option<&mut PointLight>,
+        Option<&mut SpotLight>,
+    )>,
+) {
+    for (entity, style, children) in &q_style {
+        let brightness = style.brightness(time.elapsed_secs());
+        let lights = std::iter::once(entity)
+            .chain(children.into_iter().flat_map(|children| children.iter()));
+        for light in lights {
+            let Ok((unstyled, point_light, spot_light)) = q_light.get_mut(light) else {
+                continue;
+            };
+            let intensity = unstyled.0 * brightness;
+            if let Some(mut light) = point_light {
+                if light.intensity != intensity {
+                    light.intensity = intensity;
+                }
+            }
+            if let Some(mut light) = spot_light {
+                if light.intensity != intensity {
+                    light.intensity = intensity;
+                }
+            }
+        }
+    }
+}
@@ -3,9 +3,17 @@
 //! Afterwards, we still need to add new props to the `LevelAssets` struct to preload them for a given level.
 use bevy::prelude::*;
 
-mod brush_entity;
+pub(crate) mod breakable;
+pub(crate) mod brush_entity;
+pub(crate) mod container;
 mod effects;
 mod generic;
+pub(crate) mod impact_sound;
+pub(crate) mod item;
+pub(crate) mod lamp;
+pub(crate) mod light_style;
+pub(crate) mod physics_material;
+pub(crate) mod readable;
 mod setup;
 mod specific;
 
@@ -16,5 +24,13 @@ pub(super) fn plugin(app: &mut App) {
         effects::plugin,
         generic::plugin,
         brush_entity::plugin,
+        item::plugin,
+        container::plugin,
+        readable::plugin,
+        impact_sound::plugin,
+        breakable::plugin,
+        physics_material::plugin,
+        lamp::plugin,
+        light_style::plugin,
     ));
 }
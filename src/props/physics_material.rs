@@ -0,0 +1,290 @@
+//! Physics materials decide how heavy, slippery and bouncy something is, and what it sounds like to hit it or walk on it.
+//!
+//! Props get their [`PhysicsMaterial`] from their class, which a mapper can change through the `physics_material` property in TrenchBroom.
+//! Brush textures get theirs from the `physics_material` property in their `.toml`, e.g.
+//! ```toml
+//! [properties]
+//! physics_material = "stone"
+//! ```
+//! Level geometry is merged into one collider per brush entity, so a brush entity only gets the friction and restitution
+//! of its textures' material when all of its textures agree on one. Otherwise, the material only decides what walking on it sounds like.
+
+use avian3d::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use super::impact_sound::ImpactSurface;
+use crate::{PostPhysicsAppSystems, screens::Screen, third_party::avian3d::ReportCollisions};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PhysicsMaterial>();
+    app.register_type::<PhysicsMaterialOverride>();
+    app.register_material_property(TEXTURE_PHYSICS_MATERIAL);
+    app.add_observer(override_physics_material);
+    app.add_observer(apply_physics_material);
+    app.add_systems(
+        Update,
+        apply_texture_physics_material
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+/// What a prop is made of. Props that have this get their colliders' density, friction and restitution from it.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(ReportCollisions)]
+pub(crate) enum PhysicsMaterial {
+    #[default]
+    Wood,
+    Metal,
+    Stone,
+    Glass,
+    Cloth,
+    Cardboard,
+}
+
+/// What walking on a surface sounds like.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum FootstepSurface {
+    #[default]
+    Stone,
+    Wood,
+    Metal,
+    Soft,
+}
+
+impl PhysicsMaterial {
+    pub(crate) const ALL: [Self; 6] = [
+        Self::Wood,
+        Self::Metal,
+        Self::Stone,
+        Self::Glass,
+        Self::Cloth,
+        Self::Cardboard,
+    ];
+
+    /// The material with the given name as written in TrenchBroom, e.g. `stone`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|material| material.name() == name)
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Wood => "wood",
+            Self::Metal => "metal",
+            Self::Stone => "stone",
+            Self::Glass => "glass",
+            Self::Cloth => "cloth",
+            Self::Cardboard => "cardboard",
+        }
+    }
+
+    /// In kg/m^3. Props are mostly hollow, so this is lower than the raw material's density for metal and cardboard.
+    pub(crate) fn density(self) -> f32 {
+        match self {
+            // About the density of oak wood (600-800 kg/m^3)
+            Self::Wood => 800.0,
+            Self::Metal => 2_000.0,
+            Self::Stone => 2_400.0,
+            Self::Glass => 2_500.0,
+            Self::Cloth => 300.0,
+            Self::Cardboard => 200.0,
+        }
+    }
+
+    pub(crate) fn friction(self) -> f32 {
+        match self {
+            Self::Wood => 0.5,
+            Self::Metal => 0.4,
+            Self::Stone => 0.7,
+            Self::Glass => 0.3,
+            Self::Cloth => 0.9,
+            Self::Cardboard => 0.6,
+        }
+    }
+
+    pub(crate) fn restitution(self) -> f32 {
+        match self {
+            Self::Wood => 0.2,
+            Self::Metal => 0.15,
+            Self::Stone => 0.05,
+            Self::Glass => 0.1,
+            Self::Cloth => 0.0,
+            Self::Cardboard => 0.1,
+        }
+    }
+
+    pub(crate) fn impact_surface(self) -> ImpactSurface {
+        match self {
+            Self::Wood => ImpactSurface::Wood,
+            Self::Metal => ImpactSurface::Metal,
+            Self::Stone => ImpactSurface::Stone,
+            Self::Glass => ImpactSurface::Glass,
+            Self::Cloth => ImpactSurface::Cloth,
+            Self::Cardboard => ImpactSurface::Cardboard,
+        }
+    }
+
+    pub(crate) fn footstep_surface(self) -> FootstepSurface {
+        match self {
+            Self::Wood | Self::Cardboard => FootstepSurface::Wood,
+            Self::Metal => FootstepSurface::Metal,
+            Self::Stone | Self::Glass => FootstepSurface::Stone,
+            Self::Cloth => FootstepSurface::Soft,
+        }
+    }
+}
+
+/// Lets a mapper change what a prop is made of.
+#[derive(BaseClass, Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct PhysicsMaterialOverride {
+    /// One of `wood`, `metal`, `stone`, `glass`, `cloth` or `cardboard`. Leave empty to keep the prop's own material.
+    pub(crate) physics_material: String,
+}
+
+/// The `physics_material` property of a brush texture.
+const TEXTURE_PHYSICS_MATERIAL: MaterialProperty<String> =
+    MaterialProperty::new("physics_material");
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn override_physics_material(
+    trigger: Trigger<OnAdd, PhysicsMaterialOverride>,
+    q_override: Query<&PhysicsMaterialOverride>,
+    mut commands: Commands,
+) {
+    let name = &q_override.get(trigger.target()).unwrap().physics_material;
+    if name.is_empty() {
+        return;
+    }
+    match PhysicsMaterial::from_name(name) {
+        Some(material) => {
+            commands.entity(trigger.target()).insert(material);
+        }
+        None => warn!("Unknown physics material \"{name}\", keeping the prop's own material"),
+    }
+}
+
+/// Colliders are only created once the prop's model is loaded, so by now the material is known.
+/// A [`ColliderDensity`] on the prop itself takes precedence over the material's density.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn apply_physics_material(
+    trigger: Trigger<OnAdd, ColliderOf>,
+    q_collider_of: Query<&ColliderOf>,
+    q_material: Query<(&PhysicsMaterial, Option<&ColliderDensity>)>,
+    mut commands: Commands,
+) {
+    let collider = trigger.target();
+    let Ok(collider_of) = q_collider_of.get(collider) else {
+        return;
+    };
+    let Ok((material, density)) = q_material.get(collider_of.body) else {
+        return;
+    };
+    let density = density.map_or(material.density(), |density| density.0);
+    commands.entity(collider).insert((
+        ColliderDensity(density),
+        Friction::new(material.friction()),
+        Restitution::new(material.restitution()),
+    ));
+}
+
+/// Marks a collider whose textures were already looked at by [`apply_texture_physics_material`].
+#[derive(Component, Debug, Clone, Copy)]
+struct TexturesChecked;
+
+/// Gives brush entities the friction and restitution of their textures' material, if all of their textures agree on one.
+/// The textures' materials are loaded after the level is spawned, so this waits until all of them are available.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn apply_texture_physics_material(
+    q_brush: Query<
+        (Entity, &Children),
+        (
+            Or<(With<Collider>, With<RigidBody>)>,
+            Without<PhysicsMaterial>,
+            Without<TexturesChecked>,
+        ),
+    >,
+    q_texture: Query<&GenericMaterial3d>,
+    generic_materials: Res<Assets<GenericMaterial>>,
+    mut commands: Commands,
+) {
+    'brushes: for (brush, children) in &q_brush {
+        let mut materials = Vec::new();
+        for texture in q_texture.iter_many(children) {
+            let Some(generic_material) = generic_materials.get(&texture.0) else {
+                continue 'brushes;
+            };
+            let material = generic_material
+                .get_property(TEXTURE_PHYSICS_MATERIAL)
+                .ok()
+                .and_then(|name| PhysicsMaterial::from_name(name));
+            materials.push(material);
+        }
+        commands.entity(brush).insert(TexturesChecked);
+        let Some(&Some(material)) = materials.first() else {
+            continue;
+        };
+        if materials.iter().any(|other| *other != Some(material)) {
+            continue;
+        }
+        // Level geometry is static, so the density does not matter.
+        commands.entity(brush).insert((
+            Friction::new(material.friction()),
+            Restitution::new(material.restitution()),
+        ));
+    }
+}
+
+/// Finds out what something is standing on.
+#[derive(SystemParam)]
+pub(crate) struct GroundMaterial<'w, 's> {
+    q_collider_of: Query<'w, 's, &'static ColliderOf>,
+    q_material: Query<'w, 's, &'static PhysicsMaterial>,
+    q_texture: Query<'w, 's, &'static GenericMaterial3d>,
+    generic_materials: Res<'w, Assets<GenericMaterial>>,
+    mesh_ray_cast: MeshRayCast<'w, 's>,
+}
+
+/// How far below the ray origin the ground may be.
+const MAX_GROUND_DISTANCE: f32 = 2.0;
+
+impl GroundMaterial<'_, '_> {
+    /// The material of the `ground` collider, looking at the texture right below `origin` for level geometry.
+    pub(crate) fn get(&mut self, ground: Entity, origin: Vec3) -> Option<PhysicsMaterial> {
+        let body = self
+            .q_collider_of
+            .get(ground)
+            .map_or(ground, |collider_of| collider_of.body);
+        if let Ok(material) = self.q_material.get(body) {
+            return Some(*material);
+        }
+        let q_texture = &self.q_texture;
+        let filter = |entity| q_texture.contains(entity);
+        let settings = MeshRayCastSettings::default()
+            .with_filter(&filter)
+            .with_visibility(RayCastVisibility::Any)
+            // Texture meshes span the whole brush entity, so their bounding boxes overlap a lot.
+            .never_early_exit();
+        let (texture, hit) = self
+            .mesh_ray_cast
+            .cast_ray(Ray3d::new(origin, Dir3::NEG_Y), &settings)
+            .first()?;
+        if hit.distance > MAX_GROUND_DISTANCE {
+            return None;
+        }
+        let texture = self.q_texture.get(*texture).ok()?;
+        let name = self
+            .generic_materials
+            .get(&texture.0)?
+            .get_property(TEXTURE_PHYSICS_MATERIAL)
+            .ok()?;
+        PhysicsMaterial::from_name(name)
+    }
+}
@@ -0,0 +1,147 @@
+//! Notes, books and signs the player can read. A [`Readable`] points to a text or markdown file in the assets directory.
+//! Pages are separated by a line containing only `---`. Reading happens in the reading menu, see `menus::reading`.
+
+use avian3d::prelude::*;
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use super::setup::static_bundle_from_scene;
+use crate::{
+    gameplay::interaction::{
+        Interactable, Interacted, InteractionAction, nameplate::NameplateLabel,
+    },
+    menus::Menu,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Readable>();
+    app.init_asset::<ReadableText>();
+    app.init_asset_loader::<ReadableTextLoader>();
+    app.add_observer(setup_readable);
+    app.add_observer(read);
+}
+
+#[derive(PointClass, Component, Debug, Clone, Default, Reflect)]
+#[reflect(QuakeClass, Component, Default, Debug)]
+#[base(Transform, Visibility, NameplateLabel)]
+pub(crate) struct Readable {
+    /// The title shown above the text.
+    pub(crate) title: String,
+    /// The `.txt` or `.md` file to show, relative to the assets directory.
+    pub(crate) text: String,
+    /// The glTF file the readable is displayed with, relative to the assets directory.
+    pub(crate) model: String,
+}
+
+/// The pages of a text file loaded for a [`Readable`].
+#[derive(Asset, TypePath, Debug, Clone)]
+pub(crate) struct ReadableText {
+    pub(crate) pages: Vec<String>,
+}
+
+impl ReadableText {
+    fn parse(text: &str) -> Self {
+        let mut pages = vec![String::new()];
+        for line in text.lines() {
+            if line.trim() == "---" {
+                pages.push(String::new());
+            } else {
+                let page = pages.last_mut().unwrap();
+                page.push_str(line);
+                page.push('\n');
+            }
+        }
+        let pages = pages
+            .into_iter()
+            .map(|page| page.trim().to_string())
+            .filter(|page| !page.is_empty())
+            .collect();
+        Self { pages }
+    }
+}
+
+#[derive(Default)]
+struct ReadableTextLoader;
+
+impl AssetLoader for ReadableTextLoader {
+    type Asset = ReadableText;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ReadableText::parse(&String::from_utf8_lossy(&bytes)))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt", "md"]
+    }
+}
+
+/// The text of a [`Readable`], loaded as soon as it is spawned so that reading it does not have to wait.
+#[derive(Component, Debug, Clone)]
+pub(crate) struct ReadableTextHandle(pub(crate) Handle<ReadableText>);
+
+/// The [`Readable`] shown in the reading menu, along with the page the player is on.
+#[derive(Resource, Debug, Clone)]
+pub(crate) struct CurrentReadable {
+    pub(crate) title: String,
+    pub(crate) text: Handle<ReadableText>,
+    pub(crate) page: usize,
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_readable(
+    trigger: Trigger<OnAdd, Readable>,
+    q_readable: Query<&Readable>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let readable = q_readable.get(entity).unwrap();
+    if readable.model.is_empty() {
+        error!(
+            "Readable \"{}\" has no model, so it can't be read.",
+            readable.title
+        );
+        return;
+    }
+    let model = asset_server.load(GltfAssetLabel::Scene(0).from_asset(readable.model.clone()));
+    commands.entity(entity).insert((
+        static_bundle_from_scene(model, ColliderConstructor::ConvexHullFromMesh),
+        ReadableTextHandle(asset_server.load(readable.text.clone())),
+        Interactable::new("Read", InteractionAction::Read),
+    ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn read(
+    trigger: Trigger<Interacted>,
+    q_readable: Query<(&Readable, &ReadableTextHandle)>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut commands: Commands,
+) {
+    if trigger.event().0 != InteractionAction::Read {
+        return;
+    }
+    let Ok((readable, text)) = q_readable.get(trigger.target()) else {
+        return;
+    };
+    commands.insert_resource(CurrentReadable {
+        title: readable.title.clone(),
+        text: text.0.clone(),
+        page: 0,
+    });
+    next_menu.set(Menu::Reading);
+}
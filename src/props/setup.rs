@@ -2,6 +2,7 @@
 //! A *dynamic* prop in the context of this file is a prop that is influenced by physics,
 //! while a *static* prop is unmovable terrain.
 
+use super::physics_material::PhysicsMaterial;
 use crate::third_party::avian3d::CollisionLayer;
 use crate::third_party::bevy_landmass::NavMeshAffectorParent;
 use crate::third_party::bevy_trenchbroom::LoadTrenchbroomModel as _;
@@ -24,49 +25,24 @@ pub(crate) fn setup_static_prop_with_convex_hull<T: QuakeClass>(
     commands.entity(trigger.target()).insert(bundle);
 }
 
-#[cfg_attr(feature = "hot_patch", hot)]
-pub(crate) fn setup_nonphysical_prop<T: QuakeClass>(
-    trigger: Trigger<OnAdd, T>,
-    asset_server: Res<AssetServer>,
-    mut commands: Commands,
-) {
-    let model = asset_server.load_trenchbroom_model::<T>();
-    commands.entity(trigger.target()).insert(SceneRoot(model));
-}
-
-#[cfg_attr(feature = "hot_patch", hot)]
-pub(crate) fn setup_static_prop_with_convex_decomposition<T: QuakeClass>(
-    trigger: Trigger<OnAdd, T>,
-    asset_server: Res<AssetServer>,
-    mut commands: Commands,
-) {
-    let bundle = static_bundle::<T>(
-        &asset_server,
-        ColliderConstructor::ConvexDecompositionFromMesh,
-    );
-    commands.entity(trigger.target()).insert(bundle);
-}
-
-#[cfg_attr(feature = "hot_patch", hot)]
-pub(crate) fn setup_dynamic_prop_with_convex_hull<T: QuakeClass>(
-    trigger: Trigger<OnAdd, T>,
-    asset_server: Res<AssetServer>,
-    mut commands: Commands,
-) {
-    let bundle = dynamic_bundle::<T>(&asset_server, ColliderConstructor::ConvexHullFromMesh);
-    commands.entity(trigger.target()).insert(bundle);
-}
-
 pub(crate) fn dynamic_bundle<T: QuakeClass>(
     asset_server: &AssetServer,
     constructor: ColliderConstructor,
 ) -> impl Bundle {
     let model = asset_server.load_trenchbroom_model::<T>();
+    dynamic_bundle_from_scene(model, constructor)
+}
+
+/// Like [`dynamic_bundle`], but for a model that is not known at compile time.
+pub(crate) fn dynamic_bundle_from_scene(
+    model: Handle<Scene>,
+    constructor: ColliderConstructor,
+) -> impl Bundle {
     (
         ColliderConstructorHierarchy::new(constructor)
             .with_default_layers(CollisionLayers::new(CollisionLayer::Prop, LayerMask::ALL))
-            // About the density of oak wood (600-800 kg/m^3)
-            .with_default_density(800.0),
+            // Props with a `PhysicsMaterial` get the density of their material instead.
+            .with_default_density(PhysicsMaterial::default().density()),
         RigidBody::Dynamic,
         // `TnuaNotPlatform` ensures that the character controller will not try to walk on the prop.
         // Removing this will make it so that throwing a prop at a controller sends them flying so that they stand on top of it.
@@ -80,6 +56,14 @@ pub(crate) fn static_bundle<T: QuakeClass>(
     constructor: ColliderConstructor,
 ) -> impl Bundle {
     let model = asset_server.load_trenchbroom_model::<T>();
+    static_bundle_from_scene(model, constructor)
+}
+
+/// Like [`static_bundle`], but for a model that is not known at compile time.
+pub(crate) fn static_bundle_from_scene(
+    model: Handle<Scene>,
+    constructor: ColliderConstructor,
+) -> impl Bundle {
     (
         ColliderConstructorHierarchy::new(constructor).with_default_layers(CollisionLayers::new(
             CollisionLayer::Default,
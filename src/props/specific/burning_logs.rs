@@ -1,3 +1,7 @@
+//! A fire of burning logs. The fire can be put out by using it or by throwing the item named in `extinguish_item` into it,
+//! and lit again by using it with the item named in `ignite_item` in the inventory.
+//! Firing its `targetname` lights or puts out the fire as well. Whether the fire burns is part of the save game.
+
 use avian3d::prelude::*;
 #[cfg(feature = "hot_patch")]
 use bevy_simple_subsecond_system::hot;
@@ -5,39 +9,103 @@ use bevy_trenchbroom::prelude::*;
 
 use crate::{
     PostPhysicsAppSystems,
-    props::{effects::disable_shadow_casting_on_instance_ready, setup::static_bundle},
+    audio::{Ambience, PROP_SPATIAL_SCALE, spatial_sound_effect},
+    gameplay::{
+        hint::ShowHint,
+        interaction::{Interactable, Interacted, InteractionAction},
+        player::{Player, inventory::Inventory},
+        targets::{Activate, TargetName},
+    },
+    props::{
+        effects::disable_shadow_casting_on_instance_ready,
+        item::Item,
+        light_style::{LightStyle, UnstyledIntensity},
+        setup::static_bundle,
+    },
+    save::{SaveGame, level_key},
     screens::Screen,
+    third_party::avian3d::{ReportCollisions, colliding_bodies},
 };
 #[cfg(feature = "native")]
 use crate::{RenderLayer, asset_tracking::LoadResource as _};
-use bevy::{
-    audio::{SpatialScale, Volume},
-    prelude::*,
-};
+use bevy::{audio::Volume, prelude::*};
 
 pub(super) fn plugin(app: &mut App) {
     #[cfg(feature = "native")]
     // This causes https://github.com/bevyengine/bevy/issues/18980
     app.load_resource::<BurningLogsAssets>();
-    app.register_type::<Flicker>();
+    app.add_observer(setup_burning_logs);
+    app.add_observer(set_lit);
+    app.add_observer(use_fire);
+    app.add_observer(toggle_fire_on_activation);
     app.add_systems(
         Update,
-        flicker_light
+        (douse_fires, fade_fire_lights)
             .run_if(in_state(Screen::Gameplay))
             .in_set(PostPhysicsAppSystems::Update),
     );
-    app.add_observer(setup_burning_logs);
     #[cfg(feature = "native")]
     app.add_observer(particles::add_particle_effects);
+    #[cfg(feature = "native")]
+    app.add_systems(
+        Update,
+        particles::toggle_particle_emission
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+    #[cfg(not(feature = "native"))]
+    app.add_plugins(cpu_particles::plugin);
     app.register_type::<BurningLogs>();
+    app.register_type::<FireLight>();
 }
 
-#[derive(PointClass, Component, Debug, Reflect)]
+#[derive(PointClass, Component, Debug, Clone, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, TargetName)]
 #[model("models/darkmod/fireplace/burntwood.gltf")]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
-pub(crate) struct BurningLogs;
+#[require(ReportCollisions)]
+pub(crate) struct BurningLogs {
+    /// Whether the fire burns.
+    pub(crate) lit: bool,
+    /// The ID of the item that lights the fire. Leave empty to only light it through its `targetname`.
+    pub(crate) ignite_item: String,
+    /// The ID of the item that puts out the fire when it is thrown or dropped into it.
+    pub(crate) extinguish_item: String,
+}
+
+impl Default for BurningLogs {
+    fn default() -> Self {
+        Self {
+            lit: true,
+            ignite_item: "torch".to_string(),
+            extinguish_item: "water".to_string(),
+        }
+    }
+}
+
+impl BurningLogs {
+    fn prompt(&self) -> &'static str {
+        if self.lit { "Put out" } else { "Light" }
+    }
+}
+
+/// The light of a [`BurningLogs`]. Holds the light's intensity while the fire burns.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+struct FireLight(f32);
+
+/// The crackling of a [`BurningLogs`] that burns.
+#[derive(Component, Debug, Clone, Copy)]
+struct FireLoop;
+
+/// The [`level_key`] of a [`BurningLogs`].
+#[derive(Component, Debug, Clone)]
+struct FireKey(String);
+
+/// Triggered on a [`BurningLogs`] to light it or put it out.
+#[derive(Event, Debug, Clone, Copy)]
+struct SetLit(bool);
 
 #[derive(Resource, Asset, Clone, TypePath)]
 struct BurningLogsAssets {
@@ -68,28 +136,36 @@ pub(crate) const TEXTURE_PATH: &str = {
     }
 };
 const SOUND_PATH: &str = "audio/music/loop_flames_03.ogg";
+const HISS_PATH: &str = "audio/sound_effects/fire_hiss.ogg";
 
 const BASE_INTENSITY: f32 = 150_000.0;
 
+/// How long the light takes to die down or flare up, in seconds.
+const LIGHT_FADE_TIME: f32 = 1.0;
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn setup_burning_logs(
     trigger: Trigger<OnAdd, BurningLogs>,
+    mut q_fire: Query<(&mut BurningLogs, &Transform)>,
+    save_game: Res<SaveGame>,
     asset_server: Res<AssetServer>,
     mut commands: Commands,
 ) {
+    let entity = trigger.target();
+    let (mut fire, transform) = q_fire.get_mut(entity).unwrap();
+    let key = level_key("fire", transform.translation);
+    if let Some(&lit) = save_game.fires.get(&key) {
+        fire.lit = lit;
+    }
     let static_bundle =
         static_bundle::<BurningLogs>(&asset_server, ColliderConstructor::ConvexHullFromMesh);
-    let sound_effect: Handle<AudioSource> = asset_server.load(SOUND_PATH);
 
     commands
-        .entity(trigger.target())
+        .entity(entity)
         .insert((
             static_bundle,
-            AudioPlayer(sound_effect),
-            PlaybackSettings::LOOP
-                .with_spatial(true)
-                .with_volume(Volume::Linear(0.25))
-                .with_spatial_scale(SpatialScale::new(0.3)),
+            FireKey(key),
+            Interactable::new(fire.prompt(), InteractionAction::Toggle),
         ))
         .observe(disable_shadow_casting_on_instance_ready)
         .with_child((
@@ -103,21 +179,153 @@ fn setup_burning_logs(
                 ..default()
             },
             Transform::from_xyz(0.0, 0.2, 0.0),
-            Flicker,
+            LightStyle::new(LightStyle::FLICKER),
+            FireLight(BASE_INTENSITY),
         ));
+    if fire.lit {
+        commands
+            .entity(entity)
+            .with_child(fire_loop(asset_server.load(SOUND_PATH)));
+    }
+}
+
+fn fire_loop(handle: Handle<AudioSource>) -> impl Bundle {
+    (
+        Name::new("Fire Loop"),
+        AudioPlayer(handle),
+        PlaybackSettings::LOOP
+            .with_spatial(true)
+            .with_volume(Volume::Linear(0.25))
+            .with_spatial_scale(PROP_SPATIAL_SCALE),
+        Ambience,
+        FireLoop,
+    )
 }
 
-#[derive(Component, Debug, Reflect)]
-#[reflect(Component)]
-struct Flicker;
+#[cfg_attr(feature = "hot_patch", hot)]
+fn use_fire(
+    trigger: Trigger<Interacted>,
+    q_fire: Query<&BurningLogs>,
+    inventory: Single<&Inventory, With<Player>>,
+    mut commands: Commands,
+) {
+    if trigger.event().0 != InteractionAction::Toggle {
+        return;
+    }
+    let entity = trigger.target();
+    let Ok(fire) = q_fire.get(entity) else {
+        return;
+    };
+    if fire.lit {
+        commands.trigger_targets(SetLit(false), entity);
+    } else if inventory.get(&fire.ignite_item).is_some() {
+        commands.trigger_targets(SetLit(true), entity);
+    } else {
+        commands.trigger(ShowHint("You need something to light it with.".to_string()));
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn toggle_fire_on_activation(
+    trigger: Trigger<Activate>,
+    q_fire: Query<&BurningLogs>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    if let Ok(fire) = q_fire.get(entity) {
+        commands.trigger_targets(SetLit(!fire.lit), entity);
+    }
+}
+
+/// Puts out fires that an extinguishing item was thrown or dropped into. The item is used up.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn douse_fires(
+    mut collision_started: EventReader<CollisionStarted>,
+    q_collider_of: Query<&ColliderOf>,
+    q_fire: Query<&BurningLogs>,
+    q_item: Query<&Item>,
+    mut commands: Commands,
+) {
+    for collision in collision_started.read() {
+        let [body1, body2] = colliding_bodies(collision, &q_collider_of);
+        for (fire_entity, item_entity) in [(body1, body2), (body2, body1)] {
+            let (Ok(fire), Ok(item)) = (q_fire.get(fire_entity), q_item.get(item_entity)) else {
+                continue;
+            };
+            if !fire.lit || fire.extinguish_item.is_empty() || item.item != fire.extinguish_item {
+                continue;
+            }
+            commands.trigger_targets(SetLit(false), fire_entity);
+            commands.entity(item_entity).try_despawn();
+        }
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn set_lit(
+    trigger: Trigger<SetLit>,
+    mut q_fire: Query<(
+        &mut BurningLogs,
+        &FireKey,
+        &mut Interactable,
+        Option<&Children>,
+    )>,
+    q_fire_loop: Query<(), With<FireLoop>>,
+    mut save_game: ResMut<SaveGame>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let lit = trigger.event().0;
+    let Ok((mut fire, key, mut interactable, children)) = q_fire.get_mut(entity) else {
+        return;
+    };
+    if fire.lit == lit {
+        return;
+    }
+    fire.lit = lit;
+    interactable.prompt = fire.prompt().to_string();
+    save_game.fires.insert(key.0.clone(), lit);
+    if lit {
+        commands
+            .entity(entity)
+            .with_child(fire_loop(asset_server.load(SOUND_PATH)));
+        return;
+    }
+    let fire_loops = children
+        .into_iter()
+        .flat_map(|children| children.iter())
+        .filter(|child| q_fire_loop.contains(*child));
+    for fire_loop in fire_loops {
+        commands.entity(fire_loop).despawn();
+    }
+    commands
+        .entity(entity)
+        .with_child(spatial_sound_effect(asset_server.load(HISS_PATH)));
+}
 
+/// The fire's light has a [`LightStyle`], so we fade the intensity the style is applied to.
 #[cfg_attr(feature = "hot_patch", hot)]
-fn flicker_light(time: Res<Time>, mut query: Query<&mut PointLight, With<Flicker>>) {
-    for mut light in &mut query {
-        let flickers_per_second = 20.0;
-        let flicker_percentage = 0.1;
-        let flicker = (time.elapsed_secs() * flickers_per_second).sin();
-        light.intensity = BASE_INTENSITY + flicker * BASE_INTENSITY * flicker_percentage;
+fn fade_fire_lights(
+    time: Res<Time>,
+    q_fire: Query<&BurningLogs>,
+    mut q_light: Query<(&FireLight, &ChildOf, Mut<UnstyledIntensity>)>,
+) {
+    for (fire_light, child_of, mut unstyled) in &mut q_light {
+        let Ok(fire) = q_fire.get(child_of.parent()) else {
+            continue;
+        };
+        let target = if fire.lit { fire_light.0 } else { 0.0 };
+        if unstyled.0 == target {
+            continue;
+        }
+        // Fires that start out unlit should not die down when the level is loaded.
+        if unstyled.is_added() {
+            unstyled.0 = target;
+            continue;
+        }
+        let step = fire_light.0 / LIGHT_FADE_TIME * time.delta_secs();
+        unstyled.0 += (target - unstyled.0).clamp(-step, step);
     }
 }
 
@@ -140,6 +348,18 @@ mod particles {
         commands.entity(trigger.target()).insert(particle_bundle);
     }
 
+    /// Fires that are put out stop emitting, but let their last flames burn out.
+    #[cfg_attr(feature = "hot_patch", hot)]
+    pub(super) fn toggle_particle_emission(
+        mut q_fire: Query<(Ref<BurningLogs>, &mut EffectSpawner)>,
+    ) {
+        for (fire, mut spawner) in &mut q_fire {
+            if (fire.is_changed() || spawner.is_added()) && spawner.active != fire.lit {
+                spawner.active = fire.lit;
+            }
+        }
+    }
+
     fn particle_bundle(
         asset_server: &AssetServer,
         effects: &mut Assets<EffectAsset>,
@@ -237,3 +457,221 @@ mod particles {
         effects.add(effect)
     }
 }
+
+/// `bevy_hanabi` does not run on the web, so there we simulate the particles on the CPU instead,
+/// with one billboard per particle. This approximates the effect in `particles::setup_particles`.
+#[cfg(not(feature = "native"))]
+mod cpu_particles {
+    use super::*;
+
+    use crate::{RenderLayer, gameplay::player::camera::PlayerCamera};
+    use bevy::{
+        math::VectorSpace,
+        pbr::{NotShadowCaster, NotShadowReceiver},
+        render::view::RenderLayers,
+    };
+    use rand::Rng;
+    use std::f32::consts::TAU;
+
+    pub(super) fn plugin(app: &mut App) {
+        app.register_type::<FireParticleEmitter>();
+        app.register_type::<FireParticle>();
+        app.init_resource::<FireParticleAssets>();
+        app.add_observer(add_particle_emitter);
+        app.add_systems(
+            Update,
+            (emit_particles, update_particles)
+                .chain()
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(PostPhysicsAppSystems::Update),
+        );
+    }
+
+    /// Fewer particles than the native effect, since every particle is an entity.
+    const PARTICLES_PER_SECOND: f32 = 100.0;
+    const LIFETIME: f32 = 0.4;
+    /// Heat makes the flames rise.
+    const ACCELERATION: Vec3 = Vec3::new(0.0, 0.4, 0.0);
+    const MEAN_VELOCITY: Vec3 = Vec3::new(0.0, 1.5, 0.0);
+    const VELOCITY_DEVIATION: Vec3 = Vec3::new(0.2, 0.5, 0.2);
+    const SPAWN_CENTER: Vec3 = Vec3::new(0.0, 0.2, 0.0);
+    const SPAWN_RADIUS: f32 = 0.35;
+
+    /// Transparent → bright yellow → orange → dark red → transparent
+    const COLOR_GRADIENT: [(f32, Vec4); 5] = [
+        (0.0, Vec4::new(0.0, 0.0, 0.0, 0.0)),
+        (0.1, Vec4::new(1.0, 0.8, 0.0, 1.0)),
+        (0.3, Vec4::new(1.0, 0.4, 0.0, 1.0)),
+        (0.6, Vec4::new(0.6, 0.0, 0.0, 0.8)),
+        (1.0, Vec4::new(0.0, 0.0, 0.0, 0.0)),
+    ];
+    /// Small → larger → gone
+    const SIZE_GRADIENT: [(f32, f32); 3] = [(0.0, 0.2), (0.3, 0.5), (1.0, 0.0)];
+    /// The color gradient is baked into this many materials, which the particles step through as they age.
+    const COLOR_STEPS: usize = 16;
+
+    #[derive(Resource, Debug)]
+    struct FireParticleAssets {
+        mesh: Handle<Mesh>,
+        materials: Vec<Handle<StandardMaterial>>,
+    }
+
+    impl FromWorld for FireParticleAssets {
+        fn from_world(world: &mut World) -> Self {
+            let texture: Handle<Image> = world.resource::<AssetServer>().load(TEXTURE_PATH);
+            let mesh = world
+                .resource_mut::<Assets<Mesh>>()
+                .add(Rectangle::from_length(1.0));
+            let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+            let materials = (0..COLOR_STEPS)
+                .map(|step| {
+                    let color = sample(&COLOR_GRADIENT, step as f32 / (COLOR_STEPS - 1) as f32);
+                    materials.add(StandardMaterial {
+                        base_color: LinearRgba::from_vec4(color).into(),
+                        base_color_texture: Some(texture.clone()),
+                        alpha_mode: AlphaMode::Add,
+                        unlit: true,
+                        double_sided: true,
+                        cull_mode: None,
+                        ..default()
+                    })
+                })
+                .collect();
+            Self { mesh, materials }
+        }
+    }
+
+    /// Spawns [`FireParticle`]s while the fire burns.
+    #[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+    #[reflect(Component, Default, Debug)]
+    struct FireParticleEmitter {
+        /// Particles that are due but not spawned yet, since only whole particles can be spawned.
+        pending: f32,
+    }
+
+    #[derive(Component, Debug, Clone, Copy, Reflect)]
+    #[reflect(Component, Debug)]
+    struct FireParticle {
+        velocity: Vec3,
+        age: f32,
+        rotation: f32,
+    }
+
+    #[cfg_attr(feature = "hot_patch", hot)]
+    fn add_particle_emitter(trigger: Trigger<OnAdd, BurningLogs>, mut commands: Commands) {
+        commands
+            .entity(trigger.target())
+            .insert(FireParticleEmitter::default());
+    }
+
+    #[cfg_attr(feature = "hot_patch", hot)]
+    fn emit_particles(
+        time: Res<Time>,
+        mut q_emitter: Query<(&BurningLogs, &mut FireParticleEmitter, &GlobalTransform)>,
+        assets: Res<FireParticleAssets>,
+        mut commands: Commands,
+    ) {
+        let rng = &mut rand::thread_rng();
+        for (fire, mut emitter, transform) in &mut q_emitter {
+            if !fire.lit {
+                emitter.pending = 0.0;
+                continue;
+            }
+            emitter.pending += PARTICLES_PER_SECOND * time.delta_secs();
+            while emitter.pending >= 1.0 {
+                emitter.pending -= 1.0;
+                let offset = random_in_sphere(rng) * SPAWN_RADIUS;
+                let velocity = MEAN_VELOCITY
+                    + VELOCITY_DEVIATION * Vec3::new(normal(rng), normal(rng), normal(rng));
+                commands.spawn((
+                    Name::new("Fire Particle"),
+                    Transform::from_translation(transform.transform_point(SPAWN_CENTER + offset))
+                        .with_scale(Vec3::ZERO),
+                    Mesh3d(assets.mesh.clone()),
+                    MeshMaterial3d(assets.materials[0].clone()),
+                    FireParticle {
+                        velocity,
+                        age: 0.0,
+                        rotation: rng.gen_range(0.0..TAU),
+                    },
+                    NotShadowCaster,
+                    NotShadowReceiver,
+                    RenderLayers::from(RenderLayer::PARTICLES),
+                    StateScoped(Screen::Gameplay),
+                ));
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "hot_patch", hot)]
+    fn update_particles(
+        time: Res<Time>,
+        camera: Single<&GlobalTransform, With<PlayerCamera>>,
+        mut q_particle: Query<(
+            Entity,
+            &mut FireParticle,
+            &mut Transform,
+            &mut MeshMaterial3d<StandardMaterial>,
+        )>,
+        assets: Res<FireParticleAssets>,
+        mut commands: Commands,
+    ) {
+        let dt = time.delta_secs();
+        let camera_position = camera.translation();
+        for (entity, mut particle, mut transform, mut material) in &mut q_particle {
+            particle.age += dt;
+            if particle.age >= LIFETIME {
+                commands.entity(entity).despawn();
+                continue;
+            }
+            particle.velocity += ACCELERATION * dt;
+            transform.translation += particle.velocity * dt;
+
+            let progress = particle.age / LIFETIME;
+            transform.scale = Vec3::splat(sample(&SIZE_GRADIENT, progress));
+            // Face the camera, then spin around the view direction.
+            transform.look_at(camera_position, Vec3::Y);
+            transform.rotate_local_z(particle.rotation);
+
+            let step = (progress * (COLOR_STEPS - 1) as f32).round() as usize;
+            let step_material = &assets.materials[step.min(COLOR_STEPS - 1)];
+            if material.0 != *step_material {
+                material.0 = step_material.clone();
+            }
+        }
+    }
+
+    /// Samples a piecewise linear gradient at `t` in `[0, 1]`.
+    fn sample<T: VectorSpace>(keys: &[(f32, T)], t: f32) -> T {
+        let next = keys
+            .iter()
+            .position(|(key, _)| *key >= t)
+            .unwrap_or(keys.len() - 1);
+        if next == 0 {
+            return keys[0].1;
+        }
+        let (start, from) = keys[next - 1];
+        let (end, to) = keys[next];
+        from.lerp(to, ((t - start) / (end - start)).clamp(0.0, 1.0))
+    }
+
+    fn random_in_sphere(rng: &mut impl Rng) -> Vec3 {
+        loop {
+            let point = Vec3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            if point.length_squared() <= 1.0 {
+                return point;
+            }
+        }
+    }
+
+    /// A standard normally distributed number, using the Box-Muller transform.
+    fn normal(rng: &mut impl Rng) -> f32 {
+        let u1: f32 = rng.gen_range(f32::EPSILON..1.0);
+        let u2: f32 = rng.gen_range(0.0..1.0);
+        (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos()
+    }
+}
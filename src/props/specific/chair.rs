@@ -5,7 +5,14 @@ use bevy_landmass::{Character, prelude::*};
 use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
-use crate::third_party::{avian3d::CollisionLayer, bevy_trenchbroom::LoadTrenchbroomModel as _};
+use crate::{
+    gameplay::{
+        interaction::{Interactable, InteractionAction, nameplate::NameplateLabel},
+        seating::Seat,
+    },
+    props::physics_material::{PhysicsMaterial, PhysicsMaterialOverride},
+    third_party::{avian3d::CollisionLayer, bevy_trenchbroom::LoadTrenchbroomModel as _},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(setup_chair);
@@ -14,11 +21,15 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(PointClass, Component, Debug, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, PhysicsMaterialOverride, NameplateLabel)]
 #[model("models/darkmod/furniture/seating/wchair1.gltf")]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
+#[require(PhysicsMaterial)]
 pub(crate) struct Chair;
 
+/// The height of the chair's seat above the floor.
+const CHAIR_SEAT_HEIGHT: f32 = 0.45;
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn setup_chair(
     trigger: Trigger<OnAdd, Chair>,
@@ -36,11 +47,15 @@ fn setup_chair(
     commands.entity(trigger.target()).insert((
         // The chair has a fairly complex shape, so let's use a convex decomposition.
         ColliderConstructorHierarchy::new(ColliderConstructor::ConvexDecompositionFromMesh)
-            .with_default_layers(CollisionLayers::new(CollisionLayer::Prop, LayerMask::ALL))
-            // Make the chair way more dense than the default, as it feels janky to be able to push it around easily.
-            .with_default_density(10_000.0),
+            .with_default_layers(CollisionLayers::new(CollisionLayer::Prop, LayerMask::ALL)),
+        // Make the chair way more dense than its material, as it feels janky to be able to push it around easily.
+        ColliderDensity(10_000.0),
         RigidBody::Dynamic,
         // Not inserting `TnuaNotPlatform`, otherwise the player will not be able to jump on it.
         SceneRoot(model),
+        Seat {
+            height: CHAIR_SEAT_HEIGHT,
+        },
+        Interactable::new("Sit", InteractionAction::Sit),
     ));
 }
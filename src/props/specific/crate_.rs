@@ -7,7 +7,13 @@ use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
 use crate::{
-    props::setup::setup_static_prop_with_convex_hull,
+    gameplay::interaction::{lock::Lock, nameplate::NameplateLabel},
+    props::{
+        breakable::Breakable,
+        container::Container,
+        physics_material::{PhysicsMaterial, PhysicsMaterialOverride},
+        setup::setup_static_prop_with_convex_hull,
+    },
     third_party::{avian3d::CollisionLayer, bevy_trenchbroom::LoadTrenchbroomModel as _},
 };
 
@@ -20,16 +26,17 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(PointClass, Component, Debug, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, Container, Lock, NameplateLabel)]
 #[model("models/darkmod/containers/crate01_big.gltf")]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
 pub(crate) struct CrateBig;
 
 #[derive(PointClass, Component, Debug, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, Breakable, PhysicsMaterialOverride)]
 #[model("models/darkmod/containers/crate01_small.gltf")]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
+#[require(PhysicsMaterial)]
 pub(crate) struct CrateSmall;
 
 #[cfg_attr(feature = "hot_patch", hot)]
@@ -47,8 +54,7 @@ fn setup_crate_small(
     });
     commands.entity(trigger.target()).insert((
         ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh)
-            .with_default_layers(CollisionLayers::new(CollisionLayer::Prop, LayerMask::ALL))
-            .with_default_density(1_000.0),
+            .with_default_layers(CollisionLayers::new(CollisionLayer::Prop, LayerMask::ALL)),
         // Not inserting `TnuaNotPlatform`, otherwise the player will not be able to jump on it.
         SceneRoot(model),
         // The prop should be held upright.
@@ -0,0 +1,179 @@
+//! Doors that swing open around their hinge when used, away from the player. Doors can be locked through the `Lock` base class,
+//! and firing their `targetname` opens or closes them as well. Whether a door is open is part of the save game.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use bevy_trenchbroom::prelude::*;
+
+use crate::{
+    PostPhysicsAppSystems,
+    gameplay::{
+        interaction::{
+            Interactable, Interacted, InteractionAction, lock::Lock, nameplate::NameplateLabel,
+        },
+        player::Player,
+        targets::{Activate, TargetName},
+    },
+    props::setup::static_bundle,
+    save::{SaveGame, level_key},
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<DoorStainedGlass>();
+    app.register_type::<Door>();
+    app.add_observer(setup_door_stained_glass);
+    app.add_observer(open_door_on_interaction);
+    app.add_observer(open_door_on_activation);
+    app.add_systems(
+        Update,
+        swing_doors
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PostPhysicsAppSystems::Update),
+    );
+}
+
+#[derive(PointClass, Component, Debug, Reflect)]
+#[reflect(QuakeClass, Component)]
+#[base(Transform, Visibility, TargetName, Lock, NameplateLabel)]
+#[model("models/darkmod/architecture/doors/door_stained_glass_118x52.gltf")]
+#[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
+pub(crate) struct DoorStainedGlass;
+
+/// A door that swings around its hinge.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub(crate) struct Door {
+    /// The [`level_key`] of the door.
+    key: String,
+    /// Where the door is placed in the level when it is closed.
+    closed: Transform,
+    /// Where the hinge is, relative to the door's origin.
+    hinge: Vec3,
+    /// The angle around the hinge in radians. Zero is closed.
+    angle: f32,
+    /// The angle the door is swinging towards.
+    target_angle: f32,
+}
+
+/// How far doors open, in radians.
+const OPEN_ANGLE: f32 = 100_f32.to_radians();
+/// How fast doors swing, in radians per second.
+const SWING_SPEED: f32 = 120_f32.to_radians();
+/// The hinge pin of the stained glass door is about 8 inches from its origin.
+const STAINED_GLASS_HINGE: Vec3 = Vec3::new(0.2, 0.0, 0.0);
+
+impl Door {
+    fn is_open(&self) -> bool {
+        self.target_angle != 0.0
+    }
+
+    fn prompt(&self) -> &'static str {
+        if self.is_open() { "Close" } else { "Open" }
+    }
+
+    fn transform(&self) -> Transform {
+        let rotation = Quat::from_rotation_y(self.angle);
+        let around_hinge =
+            Transform::from_translation(self.hinge - rotation * self.hinge).with_rotation(rotation);
+        self.closed * around_hinge
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn setup_door_stained_glass(
+    trigger: Trigger<OnAdd, DoorStainedGlass>,
+    q_transform: Query<&Transform>,
+    save_game: Res<SaveGame>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let closed = *q_transform.get(entity).unwrap();
+    let key = level_key("door", closed.translation);
+    let angle = save_game.open_doors.get(&key).copied().unwrap_or_default();
+    let door = Door {
+        key,
+        closed,
+        hinge: STAINED_GLASS_HINGE,
+        angle,
+        target_angle: angle,
+    };
+    let bundle =
+        static_bundle::<DoorStainedGlass>(&asset_server, ColliderConstructor::ConvexHullFromMesh);
+    commands
+        .entity(entity)
+        .insert(bundle)
+        // The door moves, so it cannot be a static body.
+        .insert((
+            RigidBody::Kinematic,
+            door.transform(),
+            Interactable::new(door.prompt(), InteractionAction::Open),
+            door,
+        ));
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_door_on_interaction(
+    trigger: Trigger<Interacted>,
+    mut q_door: Query<(&mut Door, &mut Interactable)>,
+    player: Single<&GlobalTransform, With<Player>>,
+    mut save_game: ResMut<SaveGame>,
+) {
+    if trigger.event().0 != InteractionAction::Open {
+        return;
+    }
+    let Ok((mut door, mut interactable)) = q_door.get_mut(trigger.target()) else {
+        return;
+    };
+    // Swing away from the player. Positive angles swing the door towards its local -Z.
+    let player_local =
+        door.closed.rotation.inverse() * (player.translation() - door.closed.translation);
+    let direction = if player_local.z > 0.0 { -1.0 } else { 1.0 };
+    toggle_door(&mut door, &mut interactable, &mut save_game, direction);
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn open_door_on_activation(
+    trigger: Trigger<Activate>,
+    mut q_door: Query<(&mut Door, &mut Interactable)>,
+    mut save_game: ResMut<SaveGame>,
+) {
+    let Ok((mut door, mut interactable)) = q_door.get_mut(trigger.target()) else {
+        return;
+    };
+    toggle_door(&mut door, &mut interactable, &mut save_game, 1.0);
+}
+
+/// Opens a closed door in the given direction, or closes an open one.
+fn toggle_door(
+    door: &mut Door,
+    interactable: &mut Interactable,
+    save_game: &mut SaveGame,
+    direction: f32,
+) {
+    if door.is_open() {
+        door.target_angle = 0.0;
+        save_game.open_doors.remove(&door.key);
+    } else {
+        door.target_angle = OPEN_ANGLE * direction;
+        save_game
+            .open_doors
+            .insert(door.key.clone(), door.target_angle);
+    }
+    interactable.prompt = door.prompt().to_string();
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn swing_doors(time: Res<Time>, mut q_door: Query<(&mut Door, &mut Transform)>) {
+    for (mut door, mut transform) in &mut q_door {
+        if door.angle == door.target_angle {
+            continue;
+        }
+        let max_step = SWING_SPEED * time.delta_secs();
+        door.angle += (door.target_angle - door.angle).clamp(-max_step, max_step);
+        *transform = door.transform();
+    }
+}
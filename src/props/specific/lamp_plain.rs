@@ -4,7 +4,15 @@ use bevy::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
-use crate::props::{effects::disable_shadow_casting_on_instance_ready, setup::static_bundle};
+use crate::{
+    gameplay::targets::TargetName,
+    props::{
+        effects::disable_shadow_casting_on_instance_ready,
+        lamp::{Lamp, LampLight},
+        light_style::LightStyle,
+        setup::static_bundle,
+    },
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(setup_lamp_wall_electric);
@@ -13,7 +21,7 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(PointClass, Component, Debug, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, TargetName, Lamp, LightStyle)]
 #[model("models/darkmod/lights/non-extinguishable/electric_plain1_unattached.gltf")]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
 #[classname("light_lamp_plain")]
@@ -55,6 +63,7 @@ fn setup_lamp_wall_electric(
                 soft_shadows_enabled: true,
                 ..default()
             },
+            LampLight(lamp.intensity),
         ))
         .observe(disable_shadow_casting_on_instance_ready);
 }
@@ -6,7 +6,15 @@ use bevy::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
-use crate::props::{effects::disable_shadow_casting_on_instance_ready, setup::static_bundle};
+use crate::{
+    gameplay::targets::TargetName,
+    props::{
+        effects::disable_shadow_casting_on_instance_ready,
+        lamp::{Lamp, LampLight},
+        light_style::LightStyle,
+        setup::static_bundle,
+    },
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(setup_lamp_shaded);
@@ -15,7 +23,7 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(PointClass, Component, Debug, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(Transform, Visibility, TargetName, Lamp, LightStyle)]
 #[model("models/darkmod/lights/non-extinguishable/lamp_shaded03/lamp_shaded03.gltf")]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
 #[classname("light_lamp_shaded03")]
@@ -43,6 +51,7 @@ fn setup_lamp_shaded(
                     soft_shadows_enabled: true,
                     ..default()
                 },
+                LampLight(800_000.0),
                 Transform::from_xyz(0.0, 0.1, -0.25)
                     .with_rotation(Quat::from_rotation_x(TAU / 4.5)),
             )],
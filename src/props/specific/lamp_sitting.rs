@@ -5,7 +5,16 @@ use bevy::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::*;
 
-use crate::props::{effects::disable_shadow_casting_on_instance_ready, setup::dynamic_bundle};
+use crate::{
+    gameplay::targets::TargetName,
+    props::{
+        effects::disable_shadow_casting_on_instance_ready,
+        lamp::{Lamp, LampLight},
+        light_style::LightStyle,
+        physics_material::{PhysicsMaterial, PhysicsMaterialOverride},
+        setup::dynamic_bundle,
+    },
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(setup_lamp_sitting);
@@ -14,11 +23,19 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(PointClass, Component, Debug, Reflect)]
 #[reflect(QuakeClass, Component)]
-#[base(Transform, Visibility)]
+#[base(
+    Transform,
+    Visibility,
+    TargetName,
+    Lamp,
+    LightStyle,
+    PhysicsMaterialOverride
+)]
 #[model(
     "models/darkmod/lights/non-extinguishable/round_lantern_sitting/round_lantern_sitting.gltf"
 )]
 #[spawn_hooks(SpawnHooks::new().preload_model::<Self>())]
+#[require(PhysicsMaterial = PhysicsMaterial::Metal)]
 pub(crate) struct LampSitting;
 
 #[cfg_attr(feature = "hot_patch", hot)]
@@ -47,6 +64,7 @@ fn setup_lamp_sitting(
                 soft_shadows_enabled: true,
                 ..default()
             },
+            LampLight(40_000.0),
         ))
         .observe(disable_shadow_casting_on_instance_ready);
 }
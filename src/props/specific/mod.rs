@@ -5,6 +5,7 @@ use bevy::prelude::*;
 mod burning_logs;
 mod chair;
 mod crate_;
+mod door;
 mod lamp_plain;
 mod lamp_shaded;
 mod lamp_sitting;
@@ -15,6 +16,7 @@ pub(super) fn plugin(app: &mut App) {
         burning_logs::plugin,
         chair::plugin,
         crate_::plugin,
+        door::plugin,
         lamp_sitting::plugin,
         lamp_wall_electric::plugin,
         lamp_shaded::plugin,
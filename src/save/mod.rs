@@ -0,0 +1,101 @@
+//! Save games. There is a single save slot.
+//!
+//! The [`SaveGame`] resource holds everything that is saved. Gameplay modules copy their state into it when that state changes
+//! and restore their state from it when gameplay starts. The save game is read from storage on startup
+//! and written back whenever the game is paused, gameplay ends, or the app exits.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gameplay::player::{dialogue::history::DialogueHistory, inventory::Inventory},
+    menus::Menu,
+    screens::Screen,
+};
+
+mod storage;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SaveGame>();
+    app.add_systems(Startup, read_save_game);
+    app.add_systems(OnEnter(Screen::Gameplay), start_save_game);
+    app.add_systems(OnEnter(Menu::Pause), write_save_game);
+    app.add_systems(OnExit(Screen::Gameplay), write_save_game);
+    app.add_systems(Last, write_save_game.run_if(on_event::<AppExit>));
+}
+
+/// Everything that is saved. Fields default when they are missing from the stored save game,
+/// so that saves from older versions of the game can still be loaded.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct SaveGame {
+    /// Whether the player has played with this save game.
+    pub(crate) started: bool,
+    pub(crate) dialogue_history: DialogueHistory,
+    pub(crate) inventory: Inventory,
+    /// The [`level_key`]s of the items that were taken from the level, see `props::item`.
+    pub(crate) collected_items: HashSet<String>,
+    /// The [`level_key`]s of the locks the player opened, see `gameplay::interaction::lock`.
+    pub(crate) unlocked: HashSet<String>,
+    /// How far the doors the player opened are swung open, in radians, by [`level_key`], see `props::specific::door`.
+    pub(crate) open_doors: HashMap<String, f32>,
+    /// The [`level_key`]s of the containers the player opened, see `props::container`.
+    pub(crate) opened_containers: HashSet<String>,
+    /// The [`level_key`]s of the containers the player emptied.
+    pub(crate) looted_containers: HashSet<String>,
+    /// The [`level_key`]s of the props that were broken, see `props::breakable`.
+    pub(crate) broken_props: HashSet<String>,
+    /// Whether the lamps the player switched are on, by [`level_key`], see `props::lamp`.
+    pub(crate) lamps: HashMap<String, bool>,
+    /// Whether the fires the player lit or put out are burning, by [`level_key`], see `props::specific::burning_logs`.
+    pub(crate) fires: HashMap<String, bool>,
+}
+
+impl SaveGame {
+    /// Whether there is any progress worth continuing. All progress is made during gameplay,
+    /// so this doesn't need to know about every part of the save game.
+    pub(crate) fn has_progress(&self) -> bool {
+        self.started
+    }
+}
+
+/// Identifies an entity placed in the level across save games. Levels don't give their entities stable IDs,
+/// so we use what kind of entity it is and where it was placed.
+pub(crate) fn level_key(kind: &str, translation: Vec3) -> String {
+    format!(
+        "{kind}@{:.2},{:.2},{:.2}",
+        translation.x, translation.y, translation.z
+    )
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn read_save_game(mut save_game: ResMut<SaveGame>) {
+    let Some(serialized) = storage::read() else {
+        return;
+    };
+    match ron::from_str(&serialized) {
+        Ok(loaded) => *save_game = loaded,
+        Err(err) => warn!("Failed to parse save game, starting a new one: {err}"),
+    }
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn start_save_game(mut save_game: ResMut<SaveGame>) {
+    save_game.started = true;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn write_save_game(save_game: Res<SaveGame>) {
+    let serialized = match ron::ser::to_string_pretty(&*save_game, default()) {
+        Ok(serialized) => serialized,
+        Err(err) => {
+            error!("Failed to serialize save game: {err}");
+            return;
+        }
+    };
+    storage::write(&serialized);
+}
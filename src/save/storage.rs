@@ -0,0 +1,52 @@
+//! Where the save game is stored. Native builds use a file in the working directory, web builds use the browser's `localStorage`.
+
+use bevy::prelude::*;
+
+#[cfg(not(target_family = "wasm"))]
+const SAVE_PATH: &str = "save.ron";
+
+#[cfg(target_family = "wasm")]
+const STORAGE_KEY: &str = "foxtrot_save";
+
+#[cfg(not(target_family = "wasm"))]
+pub(super) fn read() -> Option<String> {
+    match std::fs::read_to_string(SAVE_PATH) {
+        Ok(serialized) => Some(serialized),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => {
+            warn!("Failed to read save game from {SAVE_PATH}: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub(super) fn write(serialized: &str) {
+    if let Err(err) = std::fs::write(SAVE_PATH, serialized) {
+        error!("Failed to write save game to {SAVE_PATH}: {err}");
+    }
+}
+
+#[cfg(target_family = "wasm")]
+pub(super) fn read() -> Option<String> {
+    local_storage()?.get_item(STORAGE_KEY).ok().flatten()
+}
+
+#[cfg(target_family = "wasm")]
+pub(super) fn write(serialized: &str) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    if storage.set_item(STORAGE_KEY, serialized).is_err() {
+        error!("Failed to write save game to local storage.");
+    }
+}
+
+#[cfg(target_family = "wasm")]
+fn local_storage() -> Option<web_sys::Storage> {
+    let storage = web_sys::window()?.local_storage().ok().flatten();
+    if storage.is_none() {
+        warn!("Local storage is not available, the game will not be saved.");
+    }
+    storage
+}
@@ -0,0 +1,199 @@
+//! Glyphs for keys and gamepad buttons, so that prompts can show the input that is actually bound.
+//! We keep track of whether the player last used the keyboard and mouse or a gamepad and show glyphs for that device.
+
+use bevy::{
+    input::{
+        gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent},
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion},
+    },
+    prelude::*,
+    ui::Val::*,
+};
+use bevy_enhanced_input::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::theme::palette::{BODY_TEXT, PANEL_BACKGROUND};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LastInputDevice>();
+    app.add_systems(PreUpdate, update_last_input_device);
+}
+
+/// The kind of device the player used most recently.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LastInputDevice {
+    #[default]
+    KeyboardMouse,
+    Gamepad,
+}
+
+/// Sticks and triggers rest slightly off-center, so small changes don't count as using the gamepad.
+const GAMEPAD_AXIS_THRESHOLD: f32 = 0.5;
+/// Same for a mouse lying on a wobbly desk.
+const MOUSE_MOTION_THRESHOLD: f32 = 2.0;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn update_last_input_device(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut mouse_button_events: EventReader<MouseButtonInput>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut gamepad_button_events: EventReader<GamepadButtonChangedEvent>,
+    mut gamepad_axis_events: EventReader<GamepadAxisChangedEvent>,
+    mut last_input_device: ResMut<LastInputDevice>,
+) {
+    let used_keyboard_mouse = keyboard_events.read().count() > 0
+        || mouse_button_events.read().count() > 0
+        || mouse_motion_events
+            .read()
+            .any(|event| event.delta.length() > MOUSE_MOTION_THRESHOLD);
+    let used_gamepad = gamepad_button_events.read().count() > 0
+        || gamepad_axis_events
+            .read()
+            .any(|event| event.value.abs() > GAMEPAD_AXIS_THRESHOLD);
+    if used_gamepad && !used_keyboard_mouse {
+        last_input_device.set_if_neq(LastInputDevice::Gamepad);
+    } else if used_keyboard_mouse && !used_gamepad {
+        last_input_device.set_if_neq(LastInputDevice::KeyboardMouse);
+    }
+}
+
+/// A single key or button to show in a prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum InputGlyph {
+    Key(String),
+    GamepadButton(GamepadButton),
+}
+
+impl InputGlyph {
+    /// The glyph for the first input bound to `A` that belongs to the given device.
+    pub(crate) fn for_action<C: InputContext, A: InputAction>(
+        actions: &Actions<C>,
+        device: LastInputDevice,
+    ) -> Option<Self> {
+        actions
+            .binding::<A>()?
+            .inputs()
+            .iter()
+            .find_map(|binding| Self::from_input(binding.input, device))
+    }
+
+    fn from_input(input: Input, device: LastInputDevice) -> Option<Self> {
+        match (input, device) {
+            (Input::Keyboard { key, .. }, LastInputDevice::KeyboardMouse) => {
+                Some(Self::Key(key_label(key)))
+            }
+            (Input::MouseButton { button, .. }, LastInputDevice::KeyboardMouse) => {
+                Some(Self::Key(mouse_button_label(button)))
+            }
+            (Input::GamepadButton(button), LastInputDevice::Gamepad) => {
+                Some(Self::GamepadButton(button))
+            }
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Key(label) => label.clone(),
+            Self::GamepadButton(button) => gamepad_button_label(*button).to_string(),
+        }
+    }
+}
+
+/// A keycap for keys and a round button for gamepads, in the colors of the usual controller layout.
+pub(crate) fn input_glyph(glyph: &InputGlyph) -> impl Bundle {
+    let (border_color, border_radius) = match glyph {
+        InputGlyph::Key(_) => (BODY_TEXT, BorderRadius::all(Px(4.0))),
+        InputGlyph::GamepadButton(button) => (gamepad_button_color(*button), BorderRadius::MAX),
+    };
+    (
+        Name::new("Input Glyph"),
+        Node {
+            min_width: Px(28.0),
+            height: Px(28.0),
+            padding: UiRect::horizontal(Px(6.0)),
+            border: UiRect::all(Px(2.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BorderColor(border_color),
+        border_radius,
+        BackgroundColor(PANEL_BACKGROUND),
+        children![(
+            Text(glyph.label()),
+            TextFont::from_font_size(16.0),
+            TextColor(BODY_TEXT),
+        )],
+    )
+}
+
+fn key_label(key: KeyCode) -> String {
+    let label = match key {
+        KeyCode::ArrowUp => "↑",
+        KeyCode::ArrowDown => "↓",
+        KeyCode::ArrowLeft => "←",
+        KeyCode::ArrowRight => "→",
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => "Shift",
+        KeyCode::ControlLeft | KeyCode::ControlRight => "Ctrl",
+        KeyCode::AltLeft | KeyCode::AltRight => "Alt",
+        KeyCode::Escape => "Esc",
+        KeyCode::Backquote => "`",
+        _ => {
+            // `KeyE` -> `E`, `Digit1` -> `1`, `Space` stays `Space`.
+            let name = format!("{key:?}");
+            return name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name)
+                .to_string();
+        }
+    };
+    label.to_string()
+}
+
+fn mouse_button_label(button: MouseButton) -> String {
+    match button {
+        MouseButton::Left => "LMB".to_string(),
+        MouseButton::Right => "RMB".to_string(),
+        MouseButton::Middle => "MMB".to_string(),
+        MouseButton::Back => "Mouse 4".to_string(),
+        MouseButton::Forward => "Mouse 5".to_string(),
+        MouseButton::Other(index) => format!("Mouse {index}"),
+    }
+}
+
+/// Labels follow the Xbox layout, which most PC players know.
+fn gamepad_button_label(button: GamepadButton) -> &'static str {
+    match button {
+        GamepadButton::South => "A",
+        GamepadButton::East => "B",
+        GamepadButton::West => "X",
+        GamepadButton::North => "Y",
+        GamepadButton::LeftTrigger => "LB",
+        GamepadButton::RightTrigger => "RB",
+        GamepadButton::LeftTrigger2 => "LT",
+        GamepadButton::RightTrigger2 => "RT",
+        GamepadButton::LeftThumb => "LS",
+        GamepadButton::RightThumb => "RS",
+        GamepadButton::Select => "View",
+        GamepadButton::Start => "Menu",
+        GamepadButton::DPadUp => "↑",
+        GamepadButton::DPadDown => "↓",
+        GamepadButton::DPadLeft => "←",
+        GamepadButton::DPadRight => "→",
+        _ => "?",
+    }
+}
+
+fn gamepad_button_color(button: GamepadButton) -> Color {
+    match button {
+        GamepadButton::South => Color::srgb(0.38, 0.71, 0.26),
+        GamepadButton::East => Color::srgb(0.85, 0.25, 0.22),
+        GamepadButton::West => Color::srgb(0.22, 0.47, 0.85),
+        GamepadButton::North => Color::srgb(0.95, 0.76, 0.2),
+        _ => BODY_TEXT,
+    }
+}
@@ -3,17 +3,27 @@
 // Unused utilities may trigger this lints undesirably.
 #![allow(dead_code)]
 
+pub(crate) mod input_glyph;
 pub(crate) mod interaction;
 pub(crate) mod palette;
+mod scroll;
+pub(crate) mod text_scale;
 pub(crate) mod widget;
 
 #[allow(unused_imports)]
 pub(crate) mod prelude {
-    pub(crate) use super::{interaction::InteractionPalette, palette as ui_palette, widget};
+    pub(crate) use super::{
+        interaction::InteractionPalette, palette as ui_palette, text_scale::ScaledFont, widget,
+    };
 }
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins(interaction::plugin);
+    app.add_plugins((
+        input_glyph::plugin,
+        interaction::plugin,
+        scroll::plugin,
+        text_scale::plugin,
+    ));
 }
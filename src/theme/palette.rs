@@ -15,5 +15,13 @@ pub(crate) const BUTTON_HOVERED_BACKGROUND: Color = Color::srgb(0.384, 0.600, 0.
 // #3d4999
 pub(crate) const BUTTON_PRESSED_BACKGROUND: Color = Color::srgb(0.239, 0.286, 0.600);
 
+/// #1c1b26, mostly opaque
+pub(crate) const PANEL_BACKGROUND: Color = Color::srgba(0.110, 0.106, 0.149, 0.92);
+
+/// #ececec
+pub(crate) const BODY_TEXT: Color = Color::srgb(0.925, 0.925, 0.925);
+/// #7b7b85
+pub(crate) const DISABLED_TEXT: Color = Color::srgb(0.482, 0.482, 0.522);
+
 /// #2b2c2f, taken from the Bevy website
 pub(crate) const SCREEN_BACKGROUND: Color = Color::srgb(0.16862746, 0.17254902, 0.18431373);
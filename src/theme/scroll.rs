@@ -0,0 +1,44 @@
+//! Scrolling for UI nodes with [`Overflow::scroll_y`]. Bevy keeps track of the [`ScrollPosition`], but does not change it on its own.
+
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    picking::hover::HoverMap,
+    prelude::*,
+};
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::PostPhysicsAppSystems;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        scroll_hovered_nodes
+            .run_if(on_event::<MouseWheel>)
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
+}
+
+/// How many pixels one line of a mouse wheel scrolls.
+const LINE_HEIGHT: f32 = 24.0;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn scroll_hovered_nodes(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    hover_map: Res<HoverMap>,
+    mut q_scroll_position: Query<&mut ScrollPosition>,
+) {
+    for event in mouse_wheel_events.read() {
+        let dy = match event.unit {
+            MouseScrollUnit::Line => event.y * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        };
+        for pointer_map in hover_map.values() {
+            for entity in pointer_map.keys() {
+                if let Ok(mut scroll_position) = q_scroll_position.get_mut(*entity) {
+                    scroll_position.offset_y -= dy;
+                }
+            }
+        }
+    }
+}
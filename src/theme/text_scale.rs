@@ -0,0 +1,62 @@
+//! A global text size setting. Text that should follow it carries a [`ScaledFont`] instead of a fixed font size.
+
+use bevy::prelude::*;
+#[cfg(feature = "hot_patch")]
+use bevy_simple_subsecond_system::hot;
+
+use crate::PostPhysicsAppSystems;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<TextScale>();
+    app.register_type::<TextScale>();
+    app.register_type::<ScaledFont>();
+
+    app.add_observer(apply_text_scale_to_new_text);
+    app.add_systems(
+        Update,
+        apply_text_scale
+            .run_if(resource_changed::<TextScale>)
+            .in_set(PostPhysicsAppSystems::ChangeUi),
+    );
+}
+
+/// The factor by which all text with a [`ScaledFont`] is scaled.
+#[derive(Resource, Reflect, Debug, Deref, DerefMut)]
+#[reflect(Resource)]
+pub(crate) struct TextScale(pub(crate) f32);
+
+impl TextScale {
+    pub(crate) const MIN: f32 = 0.5;
+    pub(crate) const MAX: f32 = 2.0;
+}
+
+impl Default for TextScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// The font size of a text before applying the [`TextScale`].
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+#[require(TextFont)]
+pub(crate) struct ScaledFont(pub(crate) f32);
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn apply_text_scale_to_new_text(
+    trigger: Trigger<OnAdd, ScaledFont>,
+    mut q_font: Query<(&ScaledFont, &mut TextFont)>,
+    text_scale: Res<TextScale>,
+) {
+    let Ok((scaled_font, mut font)) = q_font.get_mut(trigger.target()) else {
+        return;
+    };
+    font.font_size = scaled_font.0 * text_scale.0;
+}
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn apply_text_scale(mut q_font: Query<(&ScaledFont, &mut TextFont)>, text_scale: Res<TextScale>) {
+    for (scaled_font, mut font) in &mut q_font {
+        font.font_size = scaled_font.0 * text_scale.0;
+    }
+}
@@ -8,7 +8,7 @@ use bevy::{
     ui::Val::*,
 };
 
-use crate::theme::{interaction::InteractionPalette, palette::*};
+use crate::theme::{interaction::InteractionPalette, palette::*, text_scale::ScaledFont};
 
 /// A root UI node that fills the window and centers its content.
 pub(crate) fn ui_root(name: impl Into<Cow<'static, str>>) -> impl Bundle {
@@ -48,6 +48,31 @@ pub(crate) fn label_small(text: impl Into<String>) -> impl Bundle {
     label_base(text, 12.0)
 }
 
+/// Running text, e.g. the lines of a dialogue. Its size follows the text size setting.
+pub(crate) fn paragraph(text: impl Into<String>) -> impl Bundle {
+    paragraph_with_color(text, BODY_TEXT)
+}
+
+/// A [`paragraph`] with a custom text color.
+pub(crate) fn paragraph_with_color(text: impl Into<String>, color: Color) -> impl Bundle {
+    (
+        Name::new("Paragraph"),
+        Text(text.into()),
+        ScaledFont(24.0),
+        TextColor(color),
+    )
+}
+
+/// A dark, rounded background for grouping other widgets, e.g. a dialogue box. The panel's layout is provided by `node`.
+pub(crate) fn panel(name: impl Into<Cow<'static, str>>, node: Node) -> impl Bundle {
+    (
+        Name::new(name),
+        node,
+        BackgroundColor(PANEL_BACKGROUND),
+        BorderRadius::all(Px(12.0)),
+    )
+}
+
 /// A simple text label.
 fn label_base(text: impl Into<String>, font_size: f32) -> impl Bundle {
     (
@@ -78,6 +103,7 @@ where
             },
             BorderRadius::MAX,
         ),
+        TextFont::from_font_size(40.0),
     )
 }
 
@@ -98,14 +124,42 @@ where
             justify_content: JustifyContent::Center,
             ..default()
         },
+        TextFont::from_font_size(40.0),
+    )
+}
+
+/// A button with left-aligned text that fills the width of its parent, e.g. for a list of choices.
+/// Its text size follows the text size setting.
+pub(crate) fn list_button<E, B, M, I>(text: impl Into<String>, action: I) -> impl Bundle
+where
+    E: Event,
+    B: Bundle,
+    I: IntoObserverSystem<E, B, M>,
+{
+    button_base(
+        text,
+        action,
+        (
+            Node {
+                width: Percent(100.0),
+                padding: UiRect::axes(Px(16.0), Px(8.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Start,
+                ..default()
+            },
+            BorderRadius::all(Px(8.0)),
+        ),
+        ScaledFont(24.0),
     )
 }
 
-/// A simple button with text and an action defined as an [`Observer`]. The button's layout is provided by `button_bundle`.
+/// A simple button with text and an action defined as an [`Observer`]. The button's layout is provided by `button_bundle`,
+/// the size of its text by `font_bundle`.
 fn button_base<E, B, M, I>(
     text: impl Into<String>,
     action: I,
     button_bundle: impl Bundle,
+    font_bundle: impl Bundle,
 ) -> impl Bundle
 where
     E: Event,
@@ -131,7 +185,7 @@ where
                     children![(
                         Name::new("Button Text"),
                         Text(text),
-                        TextFont::from_font_size(40.0),
+                        font_bundle,
                         TextColor(BUTTON_TEXT),
                         // Don't bubble picking events from the text up to the button.
                         Pickable::IGNORE,
@@ -7,7 +7,9 @@ use bevy_simple_subsecond_system::hot;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins(PhysicsPlugins::default());
+    app.register_type::<ReportCollisions>();
     app.add_observer(enable_interpolation);
+    app.add_observer(enable_collision_events);
 }
 
 #[derive(Debug, PhysicsLayer, Default)]
@@ -33,3 +35,39 @@ fn enable_interpolation(
             .insert(TransformInterpolation);
     }
 }
+
+/// Makes the colliders of a rigid body send collision events like [`CollisionStarted`].
+/// Avian only reports collisions for colliders that ask for it, so require this on the components of bodies
+/// whose collisions you want to react to.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub(crate) struct ReportCollisions;
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn enable_collision_events(
+    trigger: Trigger<OnAdd, ColliderOf>,
+    q_collider_of: Query<&ColliderOf>,
+    q_reports_collisions: Query<(), With<ReportCollisions>>,
+    mut commands: Commands,
+) {
+    let collider = trigger.target();
+    let Ok(collider_of) = q_collider_of.get(collider) else {
+        return;
+    };
+    if q_reports_collisions.contains(collider_of.body) {
+        commands.entity(collider).insert(CollisionEventsEnabled);
+    }
+}
+
+/// The rigid bodies that the two colliders of a collision event belong to.
+/// A collider without a rigid body stands in for its own body.
+pub(crate) fn colliding_bodies(
+    CollisionStarted(collider1, collider2): &CollisionStarted,
+    q_collider_of: &Query<&ColliderOf>,
+) -> [Entity; 2] {
+    [*collider1, *collider2].map(|collider| {
+        q_collider_of
+            .get(collider)
+            .map_or(collider, |collider_of| collider_of.body)
+    })
+}
@@ -6,6 +6,8 @@ use bevy::prelude::{
 };
 use bevy_trenchbroom::prelude::*;
 
+use crate::{gameplay::targets::TargetName, props::light_style::LightStyle};
+
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<PointLight>();
     app.register_type::<DirectionalLight>();
@@ -30,8 +32,10 @@ pub(super) fn plugin(app: &mut App) {
 /// | 4000 | 300 |    | 75-100 | 40.5  |
 ///
 /// Source: [Wikipedia](https://en.wikipedia.org/wiki/Lumen_(unit)#Lighting)
+///
+/// The light can be animated with a [`LightStyle`].
 #[derive(PointClass, Component, Debug, Clone, Copy, Default, Reflect)]
-#[base(BevyPointLight)]
+#[base(BevyPointLight, TargetName, LightStyle)]
 #[iconsprite({ path: "images/point_light.png", scale: 0.1 })]
 #[reflect(QuakeClass, Component, Default, Debug)]
 #[classname("light_point")]
@@ -89,8 +93,10 @@ struct DirectionalLight;
 /// Behaves like a point light in a perfectly absorbent housing that
 /// shines light only in a given direction. The direction is taken from
 /// the transform, and can be specified with [`Transform::looking_at`](Transform::looking_at).
+///
+/// The light can be animated with a [`LightStyle`].
 #[derive(PointClass, Component, Debug, Clone, Copy, Default, Reflect)]
-#[base(BevySpotLight)]
+#[base(BevySpotLight, TargetName, LightStyle)]
 #[iconsprite({ path: "images/point_light.png", scale: 0.1 })]
 #[reflect(QuakeClass, Component, Default, Debug)]
 #[classname("light_spot")]
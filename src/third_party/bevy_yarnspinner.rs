@@ -5,28 +5,67 @@ use bevy::prelude::*;
 use bevy_simple_subsecond_system::hot;
 use bevy_trenchbroom::prelude::BaseClass;
 use bevy_yarnspinner::{events::DialogueCompleteEvent, prelude::*};
-use bevy_yarnspinner_example_dialogue_view::prelude::*;
 
-use crate::screens::Screen;
+use crate::{
+    gameplay::player::dialogue::{
+        yarn_commands::add_yarn_commands,
+        yarn_functions::{YarnGameState, add_yarn_functions},
+    },
+    screens::Screen,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<YarnNode>();
+    app.init_resource::<InvalidYarnNodes>();
 
-    app.add_plugins((
-        // In Wasm, we need to load the dialogue file manually. If we're not targeting Wasm, we can just use `YarnSpinnerPlugin::default()` instead.
-        YarnSpinnerPlugin::with_yarn_sources(vec![YarnFileSource::file("dialogue/npc.yarn")]),
-        ExampleYarnSpinnerDialogueViewPlugin::default(),
+    // The dialogue view is our own, see `gameplay::player::dialogue::view`.
+    // Wasm cannot discover the dialogue files on its own, so we pass the list generated by `build.rs` on all platforms.
+    app.add_plugins(YarnSpinnerPlugin::with_yarn_sources(
+        YARN_FILES
+            .iter()
+            .map(|path| YarnFileSource::file(*path))
+            .collect::<Vec<_>>(),
     ));
-    app.add_systems(OnEnter(Screen::Gameplay), setup_dialogue_runner);
+    app.add_systems(
+        OnEnter(Screen::Gameplay),
+        (setup_dialogue_runner, clear_invalid_yarn_nodes),
+    );
+    app.add_systems(
+        Update,
+        validate_yarn_nodes
+            .run_if(in_state(Screen::Gameplay).and(any_with_component::<DialogueRunner>)),
+    );
+    #[cfg(feature = "dev")]
+    app.add_systems(
+        Update,
+        recreate_dialogue_runner_after_hot_reload.run_if(in_state(Screen::Gameplay)),
+    );
     app.add_systems(
         OnExit(Screen::Gameplay),
         abort_all_dialogues_when_leaving_gameplay,
     );
 }
 
+/// All `.yarn` files under `assets/dialogue`, relative to the assets directory.
+const YARN_FILES: &[&str] = include!(concat!(env!("OUT_DIR"), "/yarn_files.rs"));
+
 #[cfg_attr(feature = "hot_patch", hot)]
-fn setup_dialogue_runner(mut commands: Commands, yarn_project: Res<YarnProject>) {
-    let dialogue_runner = yarn_project.create_dialogue_runner(&mut commands);
+fn setup_dialogue_runner(
+    mut commands: Commands,
+    yarn_project: Res<YarnProject>,
+    game_state: Res<YarnGameState>,
+) {
+    spawn_dialogue_runner(&mut commands, &yarn_project, &game_state);
+}
+
+fn spawn_dialogue_runner(
+    commands: &mut Commands,
+    yarn_project: &YarnProject,
+    game_state: &YarnGameState,
+) {
+    let mut dialogue_runner = yarn_project.create_dialogue_runner(commands);
+    add_yarn_commands(&mut dialogue_runner, commands);
+    add_yarn_functions(&mut dialogue_runner, game_state);
     commands.spawn((
         StateScoped(Screen::Gameplay),
         Name::new("Dialogue Runner"),
@@ -34,6 +73,36 @@ fn setup_dialogue_runner(mut commands: Commands, yarn_project: Res<YarnProject>)
     ));
 }
 
+/// The existing dialogue runner keeps the program it was created with, so it needs to be replaced when Yarn Spinner
+/// recompiles the project after a `.yarn` file changed. We wait until no dialogue is running to not cut anyone off.
+#[cfg(feature = "dev")]
+#[cfg_attr(feature = "hot_patch", hot)]
+fn recreate_dialogue_runner_after_hot_reload(
+    mut commands: Commands,
+    yarn_project: Res<YarnProject>,
+    game_state: Res<YarnGameState>,
+    q_dialogue_runner: Query<(Entity, &DialogueRunner)>,
+    mut outdated: Local<bool>,
+) {
+    // The project counts as added the first time this runs, but the runner spawned on entering gameplay is up to date.
+    if yarn_project.is_changed() && !yarn_project.is_added() {
+        *outdated = true;
+    }
+    if !*outdated
+        || q_dialogue_runner
+            .iter()
+            .any(|(_, dialogue_runner)| dialogue_runner.is_running())
+    {
+        return;
+    }
+    *outdated = false;
+    for (entity, _) in &q_dialogue_runner {
+        commands.entity(entity).despawn();
+    }
+    spawn_dialogue_runner(&mut commands, &yarn_project, &game_state);
+    info!("Recreated the dialogue runner after the Yarn project was reloaded.");
+}
+
 #[cfg_attr(feature = "hot_patch", hot)]
 fn abort_all_dialogues_when_leaving_gameplay(
     q_dialogue_runner: Query<Entity, With<DialogueRunner>>,
@@ -46,6 +115,36 @@ fn abort_all_dialogues_when_leaving_gameplay(
     }
 }
 
+/// Descriptions of all [`YarnNode`]s in the current level that point to nodes that don't exist.
+#[derive(Resource, Debug, Default, Deref, DerefMut)]
+pub(crate) struct InvalidYarnNodes(Vec<String>);
+
+#[cfg_attr(feature = "hot_patch", hot)]
+fn clear_invalid_yarn_nodes(mut invalid_yarn_nodes: ResMut<InvalidYarnNodes>) {
+    invalid_yarn_nodes.clear();
+}
+
+/// Checks the [`YarnNode`]s spawned by the level, as a typo in TrenchBroom would otherwise only show up when someone talks to the NPC.
+#[cfg_attr(feature = "hot_patch", hot)]
+fn validate_yarn_nodes(
+    q_yarn_node: Query<(NameOrEntity, &YarnNode, &Transform), Added<YarnNode>>,
+    dialogue_runner: Single<&DialogueRunner>,
+    mut invalid_yarn_nodes: ResMut<InvalidYarnNodes>,
+) {
+    for (name, yarn_node, transform) in &q_yarn_node {
+        let problem = if yarn_node.yarn_node.is_empty() {
+            "has no `yarn_node` set".to_string()
+        } else if !dialogue_runner.node_exists(&yarn_node.yarn_node) {
+            format!("references the missing node \"{}\"", yarn_node.yarn_node)
+        } else {
+            continue;
+        };
+        let description = format!("{name} at {} {problem}", transform.translation);
+        error!("YarnNode on {description}");
+        invalid_yarn_nodes.push(description);
+    }
+}
+
 pub(crate) fn is_dialogue_running(dialogue_runner: Option<Single<&DialogueRunner>>) -> bool {
     dialogue_runner.is_some_and(|dialogue_runner| dialogue_runner.is_running())
 }